@@ -0,0 +1,44 @@
+//! Compares chained operators on big-endian values with evaluating the same expression in native form
+//! with `map`, which only converts at either end.
+//!
+//! Run with `cargo bench --bench chain`.
+
+use std::hint::black_box;
+use std::time::Instant;
+use storage_endian::BEu32;
+
+const LEN: usize = 1 << 16;
+const ROUNDS: u32 = 200;
+
+// Returns the fastest time per element in nanoseconds of `ROUNDS` runs of `f` over the inputs
+fn time(inputs: &[[BEu32; 3]], out: &mut [BEu32], f: impl Fn(BEu32, BEu32, BEu32) -> BEu32) -> f64 {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for (out, &[a, b, c]) in out.iter_mut().zip(black_box(inputs)) {
+                *out = f(a, b, c);
+            }
+            black_box(&mut *out);
+            start.elapsed().as_secs_f64() * 1e9 / LEN as f64
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn main() {
+    let inputs: Vec<[BEu32; 3]> = (0..LEN as u32)
+        .map(|i| [BEu32::new(i), BEu32::new(i / 2), BEu32::new(i % 7)])
+        .collect();
+    let mut out = vec![BEu32::new(0); LEN];
+
+    let chained = time(&inputs, &mut out, |a, b, c| a + b * c - a);
+    let mapped = time(&inputs, &mut out, |a, b, c| {
+        a.map(|a| a + b.get() * c.get() - a)
+    });
+    let native = time(&inputs, &mut out, |a, b, c| {
+        let (a, b, c) = (a.get(), b.get(), c.get());
+        BEu32::new(a + b * c - a)
+    });
+    println!("chained operators: {:.3} ns/element", chained);
+    println!("map:               {:.3} ns/element", mapped);
+    println!("native:            {:.3} ns/element", native);
+}
@@ -0,0 +1,54 @@
+//! Compares `slice_radix_sort` on big-endian values with `sort_unstable`, which compares the values in
+//! storage form, for each width of integer.
+//!
+//! Run with `cargo bench --bench sort`.
+
+use std::hint::black_box;
+use std::time::Instant;
+use storage_endian::{BEi32, BEu128, BEu16, BEu32, BEu64, BEu8};
+
+const LEN: usize = 1 << 20;
+const ROUNDS: u32 = 10;
+
+// Returns the fastest time per element in nanoseconds of `ROUNDS` runs of `sort` on a copy of `values`
+fn time<V: Copy>(values: &[V], mut sort: impl FnMut(&mut [V])) -> f64 {
+    let mut copy = values.to_vec();
+    (0..ROUNDS)
+        .map(|_| {
+            copy.copy_from_slice(values);
+            let start = Instant::now();
+            sort(black_box(&mut copy));
+            start.elapsed().as_secs_f64() * 1e9 / LEN as f64
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+// Repeatable pseudo-random keys, from a xorshift generator
+fn keys() -> impl Iterator<Item = u64> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..LEN).map(move |_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+macro_rules! compare {
+    ($($name:ident: $native:ty),*) => {$({
+        let values: Vec<$name> = keys().map(|key| $name::new(key as $native)).collect();
+        let mut scratch = values.clone();
+        let radix = time(&values, |values| $name::slice_radix_sort(values, &mut scratch));
+        let unstable = time(&values, |values| values.sort_unstable());
+        println!(
+            "{:>7}: radix sort {:6.2} ns/element, sort_unstable {:6.2} ns/element",
+            stringify!($name),
+            radix,
+            unstable
+        );
+    })*};
+}
+
+fn main() {
+    compare!(BEu8: u8, BEu16: u16, BEu32: u32, BEi32: i32, BEu64: u64, BEu128: u128);
+}
@@ -0,0 +1,104 @@
+//! Probe functions for inspecting the code generated for the endian wrappers.
+//!
+//! Build with `cargo rustc --release --example codegen -- --emit asm` and look for the `probe_*`
+//! symbols in `target/release/examples/codegen-*.s`. Conversions in the native byte order should
+//! compile to a plain move, and conversions in the opposite order to a single byte swap instruction.
+//!
+//! On x86-64 this is checked by `tests/codegen.rs`, which needs updating along with the probes here.
+
+use storage_endian::{BEu128, BEu16, BEu32, BEu64, BEu8, LEu32, NEu64};
+
+#[no_mangle]
+pub extern "C" fn probe_be_u16_value(value: BEu16) -> u16 {
+    value.into()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u8_value(a: BEu8) -> u8 {
+    a.value()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_value(value: BEu32) -> u32 {
+    value.into()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u64_new(value: u64) -> BEu64 {
+    value.into()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_le_u32_value(value: LEu32) -> u32 {
+    value.into()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_ne_u64_value(value: NEu64) -> u64 {
+    value.into()
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_add(a: BEu32, b: BEu32) -> BEu32 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_eq(a: BEu32, b: u32) -> bool {
+    a == b
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_lt(a: BEu32, b: BEu32) -> bool {
+    a < b
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_and(a: BEu32) -> BEu32 {
+    a & 0x80
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_chain(a: BEu32, b: BEu32, c: BEu32) -> BEu32 {
+    a + b + c
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_chain_map(a: BEu32, b: BEu32, c: BEu32) -> BEu32 {
+    a.map(|a| a + b.get() + c.get())
+}
+
+#[no_mangle]
+pub extern "C" fn probe_be_u32_eq_wrapper(a: BEu32, b: BEu32) -> bool {
+    a == b
+}
+
+#[no_mangle]
+pub fn probe_be_u128_value(value: BEu128) -> u128 {
+    value.into()
+}
+
+#[no_mangle]
+pub fn probe_be_u32_read(buf: &[u8]) -> Option<u32> {
+    BEu32::read_from(buf, 0).map(u32::from)
+}
+
+fn main() {
+    let value = BEu32::new(0x1234_5678);
+    assert_eq!(probe_be_u32_value(value), 0x1234_5678);
+    assert_eq!(probe_be_u8_value(BEu8::new(7)), 7);
+    assert_eq!(probe_le_u32_value(LEu32::from(value)), 0x1234_5678);
+    assert_eq!(probe_be_u64_new(1).to_bytes()[7], 1);
+    assert_eq!(probe_ne_u64_value(NEu64::new(2)), 2);
+    assert_eq!(probe_be_u16_value(BEu16::new(3)), 3);
+    assert_eq!(probe_be_u32_add(value, BEu32::new(1)), 0x1234_5679);
+    assert!(probe_be_u32_eq(value, 0x1234_5678));
+    assert!(probe_be_u32_eq_wrapper(value, value));
+    let (one, two) = (BEu32::new(1), BEu32::new(2));
+    assert_eq!(probe_be_u32_chain(one, two, value), 0x1234_567b);
+    assert_eq!(probe_be_u32_chain_map(one, two, value), 0x1234_567b);
+    assert_eq!(probe_be_u32_and(BEu32::new(0x1ff)), 0x80);
+    assert!(probe_be_u32_lt(BEu32::new(0xff), value));
+    assert_eq!(probe_be_u128_value(BEu128::new(4)), 4);
+    assert_eq!(probe_be_u32_read(&value.to_bytes()), Some(0x1234_5678));
+}
@@ -0,0 +1,222 @@
+use crate::{BitOrder, ByteSource, EndianReader, EndianValue, EndianWriter, Error};
+
+/// A reader of bit fields of up to 64 bits, layered on an `EndianReader`
+///
+/// With `BitOrder::Msb0` bits are read from the most significant bit of each byte first, and the first bit
+/// read is the most significant bit of the field. With `BitOrder::Lsb0` (as used by DEFLATE) bits are read
+/// from the least significant bit first, and the first bit read is the least significant bit of the field.
+///
+/// ```
+/// use storage_endian::{BEu16, BitOrder, BitReader, EndianReader};
+///
+/// let mut bits = BitReader::new(EndianReader::new(&[0b1011_0010, 0x12, 0x34]), BitOrder::Msb0);
+/// assert_eq!(bits.read_bits(3)?, 0b101);
+/// assert!(bits.read_bit()?);
+/// assert_eq!(bits.read::<BEu16>()?, 0x1234);
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct BitReader<S> {
+    reader: EndianReader<S>,
+    order: BitOrder,
+    current: u8,
+    // Number of bits of `current` not yet read
+    available: u32,
+}
+
+impl<S: ByteSource> BitReader<S> {
+    /// Creates a bit reader starting at the current position of `reader`
+    pub fn new(reader: EndianReader<S>, order: BitOrder) -> Self {
+        Self {
+            reader,
+            order,
+            current: 0,
+            available: 0,
+        }
+    }
+
+    /// Returns the number of bits read so far
+    pub fn bit_position(&self) -> usize {
+        self.reader.position() * 8 - self.available as usize
+    }
+
+    /// Returns `true` if the next bit is the first bit of a byte
+    pub fn is_aligned(&self) -> bool {
+        self.available == 0
+    }
+
+    /// Discards the rest of the current byte, so the next read starts at a byte boundary
+    pub fn align(&mut self) {
+        self.available = 0;
+    }
+
+    /// Reads a field of `len` bits
+    ///
+    /// On error nothing is consumed if the underlying source knows how many bytes it has left (see `ByteSource`).
+    ///
+    /// # Panics
+    /// Panics if `len` is more than 64.
+    pub fn read_bits(&mut self, len: u32) -> Result<u64, Error> {
+        assert!(len <= 64, "bit field too large");
+        let needed = (len.saturating_sub(self.available) as usize).div_ceil(8);
+        if let Some(remaining) = self
+            .reader
+            .remaining_hint()
+            .filter(|&remaining| remaining < needed)
+        {
+            return Err(Error::truncated(self.reader.position(), needed, remaining));
+        }
+        let (mut value, mut done) = (0, 0);
+        while done < len {
+            if self.available == 0 {
+                self.current = self.reader.read::<u8>()?;
+                self.available = 8;
+            }
+            let take = (len - done).min(self.available);
+            let mask = (1 << take) - 1;
+            match self.order {
+                BitOrder::Msb0 => {
+                    let bits = (self.current >> (self.available - take)) as u64 & mask;
+                    value = (value << take) | bits;
+                }
+                BitOrder::Lsb0 => {
+                    let bits = (self.current >> (8 - self.available)) as u64 & mask;
+                    value |= bits << done;
+                }
+            }
+            self.available -= take;
+            done += take;
+        }
+        Ok(value)
+    }
+
+    /// Reads a single bit
+    pub fn read_bit(&mut self) -> Result<bool, Error> {
+        self.read_bits(1).map(|bit| bit != 0)
+    }
+
+    /// Discards the rest of the current byte, then reads a value from its storage order bytes
+    pub fn read<V: EndianValue>(&mut self) -> Result<V, Error> {
+        self.align();
+        self.reader.read()
+    }
+
+    /// Returns the byte reader, discarding the rest of the current byte
+    pub fn into_inner(self) -> EndianReader<S> {
+        self.reader
+    }
+}
+
+/// A writer of bit fields of up to 64 bits, layered on an `EndianWriter`
+///
+/// Bits are packed as described for `BitReader`. A partially written byte is only stored once it is
+/// complete, or when the writer is aligned, so `finish` should be called when done.
+///
+/// ```
+/// use storage_endian::{BitOrder, BitWriter, EndianWriter, LEu16};
+///
+/// let mut buf = [0; 3];
+/// let mut bits = BitWriter::new(EndianWriter::new(&mut buf), BitOrder::Lsb0);
+/// bits.write_bit(true)?;
+/// bits.write_bits(0b10, 2)?;
+/// bits.write(LEu16::new(0x1234))?;
+/// assert_eq!(bits.finish()?.position(), 3);
+/// assert_eq!(buf, [0b101, 0x34, 0x12]);
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    writer: EndianWriter<'a>,
+    order: BitOrder,
+    current: u8,
+    // Number of bits of `current` already written
+    used: u32,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Creates a bit writer starting at the current position of `writer`
+    pub fn new(writer: EndianWriter<'a>, order: BitOrder) -> Self {
+        Self {
+            writer,
+            order,
+            current: 0,
+            used: 0,
+        }
+    }
+
+    /// Returns the number of bits written so far
+    pub fn bit_position(&self) -> usize {
+        self.writer.position() * 8 + self.used as usize
+    }
+
+    /// Returns `true` if the next bit is the first bit of a byte
+    pub fn is_aligned(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Pads the current byte with zero bits and stores it, so the next write starts at a byte boundary
+    pub fn align(&mut self) -> Result<(), Error> {
+        if self.used > 0 {
+            self.writer.write(self.current)?;
+            self.current = 0;
+            self.used = 0;
+        }
+        Ok(())
+    }
+
+    /// Writes the low `len` bits of `value` as a field
+    ///
+    /// On error nothing is written.
+    ///
+    /// # Panics
+    /// Panics if `len` is more than 64.
+    pub fn write_bits(&mut self, value: u64, len: u32) -> Result<(), Error> {
+        assert!(len <= 64, "bit field too large");
+        let needed = (self.used + len) as usize / 8;
+        if self.writer.remaining() < needed {
+            return Err(Error::truncated(
+                self.writer.position(),
+                needed,
+                self.writer.remaining(),
+            ));
+        }
+        let mut left = len;
+        while left > 0 {
+            let take = left.min(8 - self.used);
+            let mask = 0xff >> (8 - take);
+            match self.order {
+                BitOrder::Msb0 => {
+                    let bits = (value >> (left - take)) as u8 & mask;
+                    self.current |= bits << (8 - self.used - take);
+                }
+                BitOrder::Lsb0 => {
+                    let bits = (value >> (len - left)) as u8 & mask;
+                    self.current |= bits << self.used;
+                }
+            }
+            self.used += take;
+            left -= take;
+            if self.used == 8 {
+                self.align()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a single bit
+    pub fn write_bit(&mut self, bit: bool) -> Result<(), Error> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Pads the current byte with zero bits, then writes the storage order bytes of a value
+    pub fn write<V: EndianValue>(&mut self, value: V) -> Result<(), Error> {
+        self.align()?;
+        self.writer.write(value)
+    }
+
+    /// Pads and stores the current byte, returning the byte writer
+    pub fn finish(mut self) -> Result<EndianWriter<'a>, Error> {
+        self.align()?;
+        Ok(self.writer)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Endianness, Primitive, BE, LE};
+use alloc::vec::Vec;
+
+macro_rules! push_methods {
+    ( $( $be:ident / $le:ident => $t:ident ),* ) => { $(
+        /// Appends a big-endian value
+        pub fn $be(&mut self, value: $t) -> &mut Self {
+            self.push_endian::<$t, BE>(value)
+        }
+        /// Appends a little-endian value
+        pub fn $le(&mut self, value: $t) -> &mut Self {
+            self.push_endian::<$t, LE>(value)
+        }
+    )* };
+}
+
+/// A growable buffer for serializing values with explicit byte orders
+///
+/// ```
+/// use storage_endian::{BEu16, EndianBuf};
+///
+/// let mut buf = EndianBuf::new();
+/// buf.push_be_u16(0).push_bytes(b"hello").push_le_u32(7);
+/// let len = buf.len() as u16;
+/// BEu16::new(len).write_into(buf.as_bytes_mut(), 0);
+/// assert_eq!(buf.as_bytes(), b"\x00\x0bhello\x07\x00\x00\x00");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EndianBuf {
+    bytes: Vec<u8>,
+}
+
+impl EndianBuf {
+    /// Creates an empty buffer
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Creates an empty buffer with space for at least `capacity` bytes
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes written
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if nothing has been written
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Removes all bytes, keeping the allocation
+    pub fn clear(&mut self) {
+        self.bytes.clear()
+    }
+
+    /// Returns the bytes written
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the bytes written, e.g. to fill in a length field afterwards
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Returns the underlying vector
+    pub fn into_vec(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Appends raw bytes
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.bytes.extend_from_slice(bytes);
+        self
+    }
+
+    /// Appends the in-memory bytes of a value, such as a wrapper (e.g. `BEu32`), `MacAddr` or `Magic`
+    pub fn push(&mut self, value: impl AsRef<[u8]>) -> &mut Self {
+        self.push_bytes(value.as_ref())
+    }
+
+    /// Appends a native value stored with byte order `E`
+    pub fn push_endian<T: Primitive, E: Endianness>(&mut self, value: T) -> &mut Self {
+        self.push_bytes(E::to_bytes(value).as_ref())
+    }
+
+    /// Appends a single byte
+    pub fn push_u8(&mut self, value: u8) -> &mut Self {
+        self.bytes.push(value);
+        self
+    }
+
+    push_methods!(
+        push_be_u16 / push_le_u16 => u16,
+        push_be_u32 / push_le_u32 => u32,
+        push_be_u64 / push_le_u64 => u64,
+        push_be_u128 / push_le_u128 => u128,
+        push_be_i16 / push_le_i16 => i16,
+        push_be_i32 / push_le_i32 => i32,
+        push_be_i64 / push_le_i64 => i64,
+        push_be_i128 / push_le_i128 => i128,
+        push_be_f32 / push_le_f32 => f32,
+        push_be_f64 / push_le_f64 => f64
+    );
+
+    /// Appends the in-memory bytes of a structure, such as a `#[repr(C)]` header made of endian wrappers
+    ///
+    /// # Safety
+    ///
+    /// `S` must not contain any padding bytes, or any other uninitialized bytes.
+    pub unsafe fn push_struct<S: Copy>(&mut self, value: &S) -> &mut Self {
+        // SAFETY: the caller guarantees that every byte of `S` is initialized
+        let bytes = unsafe {
+            core::slice::from_raw_parts(value as *const S as *const u8, core::mem::size_of::<S>())
+        };
+        self.push_bytes(bytes)
+    }
+}
+
+impl AsRef<[u8]> for EndianBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl From<EndianBuf> for Vec<u8> {
+    fn from(other: EndianBuf) -> Self {
+        other.bytes
+    }
+}
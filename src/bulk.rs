@@ -0,0 +1,700 @@
+use crate::{ByteOrder, Endian, EndianInt, Endianness, Primitive};
+use core::ops::{Bound, RangeBounds};
+
+// Shuffle mask reversing each group of `size` bytes within a 16-byte block
+#[allow(dead_code)] // only used by the SIMD implementations
+const fn mask(size: usize) -> [u8; 16] {
+    let mut mask = [0; 16];
+    let mut i = 0;
+    while i < 16 {
+        mask[i] = ((i / size) * size + size - 1 - i % size) as u8;
+        i += 1;
+    }
+    mask
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    #[cfg(target_feature = "sse2")]
+    use core::ops::Bound;
+
+    // As `swap_blocks`, using an SSSE3 byte shuffle
+    //
+    // SAFETY: the CPU must support SSSE3, and the caller guarantees `len` bytes are valid
+    #[target_feature(enable = "ssse3")]
+    pub(crate) unsafe fn swap_blocks_ssse3(
+        src: *const u8,
+        dst: *mut u8,
+        len: usize,
+        size: usize,
+    ) -> usize {
+        let mask = super::mask(size);
+        let done = len / 16 * 16;
+        // SAFETY: as above
+        unsafe {
+            let mask = _mm_loadu_si128(mask.as_ptr() as *const __m128i);
+            for offset in (0..done).step_by(16) {
+                let block = _mm_loadu_si128(src.add(offset) as *const __m128i);
+                _mm_storeu_si128(
+                    dst.add(offset) as *mut __m128i,
+                    _mm_shuffle_epi8(block, mask),
+                );
+            }
+        }
+        done
+    }
+
+    // As `swap_blocks`, using SSE2 word shuffles followed by swapping the bytes of each 16-bit word
+    //
+    // SAFETY: the CPU must support SSE2, and the caller guarantees `len` bytes are valid
+    #[cfg(target_feature = "sse2")]
+    #[target_feature(enable = "sse2")]
+    pub(crate) unsafe fn swap_blocks_sse2(
+        src: *const u8,
+        dst: *mut u8,
+        len: usize,
+        size: usize,
+    ) -> usize {
+        let done = len / 16 * 16;
+        // SAFETY: as above
+        unsafe {
+            for offset in (0..done).step_by(16) {
+                let block = _mm_loadu_si128(src.add(offset) as *const __m128i);
+                _mm_storeu_si128(dst.add(offset) as *mut __m128i, swap_lanes(block, size));
+            }
+        }
+        done
+    }
+
+    // Reverses each group of `size` bytes in a block, using word shuffles followed by swapping the bytes
+    // of each 16-bit word
+    //
+    // SAFETY: the CPU must support SSE2
+    #[cfg(target_feature = "sse2")]
+    #[target_feature(enable = "sse2")]
+    #[inline]
+    unsafe fn swap_lanes(block: __m128i, size: usize) -> __m128i {
+        let block = match size {
+            1 => return block,
+            4 => _mm_shufflehi_epi16::<0b10_11_00_01>(_mm_shufflelo_epi16::<0b10_11_00_01>(block)),
+            8 => _mm_shufflehi_epi16::<0b00_01_10_11>(_mm_shufflelo_epi16::<0b00_01_10_11>(block)),
+            16 => _mm_shuffle_epi32::<0b01_00_11_10>(_mm_shufflehi_epi16::<0b00_01_10_11>(
+                _mm_shufflelo_epi16::<0b00_01_10_11>(block),
+            )),
+            _ => block,
+        };
+        _mm_or_si128(_mm_slli_epi16::<8>(block), _mm_srli_epi16::<8>(block))
+    }
+
+    // Loads a block of bytes
+    //
+    // SAFETY: the CPU must support SSE2
+    #[cfg(target_feature = "sse2")]
+    #[target_feature(enable = "sse2")]
+    #[inline]
+    unsafe fn load(block: &[u8; 16]) -> __m128i {
+        // SAFETY: as above, and the block is 16 bytes
+        unsafe { _mm_loadu_si128(block.as_ptr() as *const __m128i) }
+    }
+
+    // Compares each signed integer of `size` (1, 2 or 4) bytes in `a` with the one in `b`
+    //
+    // SAFETY: the CPU must support SSE2
+    #[cfg(target_feature = "sse2")]
+    #[target_feature(enable = "sse2")]
+    #[inline]
+    unsafe fn cmpgt(a: __m128i, b: __m128i, size: usize) -> __m128i {
+        match size {
+            1 => _mm_cmpgt_epi8(a, b),
+            2 => _mm_cmpgt_epi16(a, b),
+            _ => _mm_cmpgt_epi32(a, b),
+        }
+    }
+
+    // Returns a bitmask of the values of `size` (1, 2 or 4) bytes in a 16-byte block within `range`,
+    // swapping them to native order first if `swap` is set
+    //
+    // SAFETY: the CPU must support SSE2
+    #[cfg(target_feature = "sse2")]
+    #[target_feature(enable = "sse2")]
+    pub(crate) unsafe fn range_block_sse2(
+        block: &[u8],
+        size: usize,
+        swap: bool,
+        range: &super::LaneRange,
+    ) -> u32 {
+        assert_eq!(block.len(), 16);
+        // SAFETY: as above, and all the blocks are 16 bytes
+        let bytes = unsafe {
+            let mut values = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+            if swap {
+                values = swap_lanes(values, size);
+            }
+            values = _mm_xor_si128(values, load(&range.bias));
+            let mut inside = _mm_cmpeq_epi8(values, values);
+            match &range.start {
+                Bound::Included(start) => {
+                    inside = _mm_andnot_si128(cmpgt(load(start), values, size), inside)
+                }
+                Bound::Excluded(start) => {
+                    inside = _mm_and_si128(cmpgt(values, load(start), size), inside)
+                }
+                Bound::Unbounded => {}
+            }
+            match &range.end {
+                Bound::Included(end) => {
+                    inside = _mm_andnot_si128(cmpgt(values, load(end), size), inside)
+                }
+                Bound::Excluded(end) => {
+                    inside = _mm_and_si128(cmpgt(load(end), values, size), inside)
+                }
+                Bound::Unbounded => {}
+            }
+            _mm_movemask_epi8(inside) as u32
+        };
+        super::value_mask(bytes, size)
+    }
+}
+
+// Copies `len` bytes from `src` to `dst` in whole 16-byte blocks, reversing each group of `size` bytes,
+// and returns the number of bytes processed. `src` and `dst` are either equal or don't overlap.
+//
+// SSSE3 is used if it's enabled at compile time or (with `std`) detected at run time, otherwise SSE2.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn swap_blocks(src: *const u8, dst: *mut u8, len: usize, size: usize) -> usize {
+    #[cfg(feature = "std")]
+    let ssse3 = std::is_x86_feature_detected!("ssse3");
+    #[cfg(not(feature = "std"))]
+    let ssse3 = cfg!(target_feature = "ssse3");
+    if ssse3 {
+        // SAFETY: SSSE3 is supported, and the caller guarantees `len` bytes are valid
+        return unsafe { x86::swap_blocks_ssse3(src, dst, len, size) };
+    }
+    #[cfg(target_feature = "sse2")]
+    // SAFETY: SSE2 is enabled at compile time, and the caller guarantees `len` bytes are valid
+    return unsafe { x86::swap_blocks_sse2(src, dst, len, size) };
+    #[cfg(not(target_feature = "sse2"))]
+    0
+}
+
+// Copies `len` bytes from `src` to `dst` in whole 16-byte blocks, reversing each group of `size` bytes,
+// and returns the number of bytes processed. `src` and `dst` are either equal or don't overlap.
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+unsafe fn swap_blocks(src: *const u8, dst: *mut u8, len: usize, size: usize) -> usize {
+    use core::arch::aarch64::{vld1q_u8, vqtbl1q_u8, vst1q_u8};
+
+    let mask = mask(size);
+    let done = len / 16 * 16;
+    // SAFETY: NEON is enabled at compile time, and the caller guarantees `len` bytes are valid
+    unsafe {
+        let mask = vld1q_u8(mask.as_ptr());
+        for offset in (0..done).step_by(16) {
+            vst1q_u8(dst.add(offset), vqtbl1q_u8(vld1q_u8(src.add(offset)), mask));
+        }
+    }
+    done
+}
+
+// Without SIMD, leave everything to the scalar loop (which LLVM vectorizes where it can)
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon"),
+)))]
+unsafe fn swap_blocks(_src: *const u8, _dst: *mut u8, _len: usize, _size: usize) -> usize {
+    0
+}
+
+// Copies `src` to `dst` (which may be the same memory), reversing the bytes of each value
+//
+// SAFETY: both pointers must be valid for `len` values, and either equal or not overlapping
+unsafe fn swap_copy<T: Primitive>(src: *const T, dst: *mut T, len: usize) {
+    let size = core::mem::size_of::<T>();
+    // SAFETY: primitive numbers have no padding, and every bit pattern is valid
+    let done = unsafe { swap_blocks(src as *const u8, dst as *mut u8, len * size, size) } / size;
+    for i in done..len {
+        // SAFETY: `i` is in bounds, as guaranteed by the caller
+        unsafe {
+            let value = src.add(i).read();
+            dst.add(i).write(T::from_le_bytes(value.to_be_bytes()));
+        }
+    }
+}
+
+// Inputs larger than this many bytes are split on their most significant key byte before sorting the
+// rest, so that the remaining passes over each part stay in cache
+const RADIX_SPLIT_BYTES: usize = 64 * 1024;
+// Values wider than this are sorted faster by comparison than by one radix pass per byte
+const RADIX_MAX_KEY_BYTES: usize = 4;
+// Parts of at most this many items are insertion sorted, as counting would cost more than comparing
+const RADIX_INSERTION_LEN: usize = 32;
+
+// Stable radix sort of `items` on key bytes `0..levels` (least significant first), using `scratch` of the
+// same length as working space, and `less` to compare keys in small parts
+fn radix_sort<S: Copy>(
+    items: &mut [S],
+    scratch: &mut [S],
+    levels: usize,
+    byte: &impl Fn(&S, usize) -> u8,
+    less: &impl Fn(&S, &S) -> bool,
+) {
+    let len = items.len();
+    if levels == 0 || len < 2 {
+        return;
+    }
+    if len <= RADIX_INSERTION_LEN {
+        return insertion_sort(items, less);
+    }
+    if core::mem::size_of_val(items) <= RADIX_SPLIT_BYTES || levels == 1 {
+        return radix_sort_lsd(items, scratch, levels, byte);
+    }
+    let top = levels - 1;
+    let mut counts = [0usize; 256];
+    for item in items.iter() {
+        counts[byte(item, top) as usize] += 1;
+    }
+    if counts.contains(&len) {
+        // Every key has the same most significant byte
+        return radix_sort(items, scratch, top, byte, less);
+    }
+    let mut starts = [0usize; 257];
+    for bucket in 0..256 {
+        starts[bucket + 1] = starts[bucket] + counts[bucket];
+    }
+    let mut next = starts;
+    for item in items.iter() {
+        let bucket = &mut next[byte(item, top) as usize];
+        scratch[*bucket] = *item;
+        *bucket += 1;
+    }
+    // Sort each part in place in `scratch`, then copy them all back
+    for part in starts.windows(2) {
+        let range = part[0]..part[1];
+        radix_sort(
+            &mut scratch[range.clone()],
+            &mut items[range],
+            top,
+            byte,
+            less,
+        );
+    }
+    items.copy_from_slice(scratch);
+}
+
+// Stable insertion sort of `items`
+fn insertion_sort<S: Copy>(items: &mut [S], less: &impl Fn(&S, &S) -> bool) {
+    for i in 1..items.len() {
+        let item = items[i];
+        let mut j = i;
+        while j > 0 && less(&item, &items[j - 1]) {
+            items[j] = items[j - 1];
+            j -= 1;
+        }
+        items[j] = item;
+    }
+}
+
+// Stable LSD radix sort of `items` on key bytes `0..levels`, using `scratch` for each pass's output
+fn radix_sort_lsd<S: Copy>(
+    items: &mut [S],
+    scratch: &mut [S],
+    levels: usize,
+    byte: &impl Fn(&S, usize) -> u8,
+) {
+    let len = items.len();
+    // Count every level's bytes in a single pass, rather than once per pass
+    let mut counts = [[0usize; 256]; 16];
+    for item in items.iter() {
+        for (level, counts) in counts[..levels].iter_mut().enumerate() {
+            counts[byte(item, level) as usize] += 1;
+        }
+    }
+    let mut in_scratch = false;
+    for (level, counts) in counts[..levels].iter_mut().enumerate() {
+        if counts.contains(&len) {
+            // Every key has the same byte at this level
+            continue;
+        }
+        let (src, dst) = if in_scratch {
+            (&*scratch, &mut *items)
+        } else {
+            (&*items, &mut *scratch)
+        };
+        let mut offset = 0;
+        for count in counts.iter_mut() {
+            offset += *count;
+            *count = offset - *count;
+        }
+        for item in src.iter() {
+            let bucket = &mut counts[byte(item, level) as usize];
+            dst[*bucket] = *item;
+            *bucket += 1;
+        }
+        in_scratch = !in_scratch;
+    }
+    if in_scratch {
+        items.copy_from_slice(scratch);
+    }
+}
+
+// Converts a bitmask of matching bytes into a bitmask of values of `size` bytes whose bytes all match
+fn value_mask(bytes: u32, size: usize) -> u32 {
+    let all = (1 << size) - 1;
+    let mut mask = 0;
+    for value in 0..16 / size {
+        if (bytes >> (value * size)) & all == all {
+            mask |= 1 << value;
+        }
+    }
+    mask
+}
+
+// Returns `true` if `T` is a signed integer, for which all ones is negative
+fn is_signed<T: Primitive>() -> bool {
+    let mut ones = T::Bytes::default();
+    ones.as_mut().fill(0xff);
+    T::from_ne_bytes(ones) < T::default()
+}
+
+// Returns an iterator over the indices of the matching values, using `block` to match the values in each
+// 16 bytes (given as both bytes and values, returning a bitmask of values) and `matches` for any left over
+fn block_search<'a, T: Primitive, E: Endianness>(
+    values: &'a [Endian<T, E>],
+    mut block: impl FnMut(&[u8], &'a [Endian<T, E>]) -> u32 + 'a,
+    matches: impl Fn(&Endian<T, E>) -> bool + 'a,
+) -> impl Iterator<Item = usize> + 'a {
+    let size = core::mem::size_of::<T>();
+    // SAFETY: primitive numbers have no padding and a byte slice has an alignment of 1
+    let bytes = unsafe {
+        core::slice::from_raw_parts(values.as_ptr() as *const u8, core::mem::size_of_val(values))
+    };
+    let mut blocks = bytes.chunks_exact(16).zip(values.chunks_exact(16 / size));
+    let (mut base, mut next_base, mut found) = (0, 0, 0u32);
+    let mut tail = bytes.len() / 16 * 16 / size;
+    core::iter::from_fn(move || loop {
+        if found != 0 {
+            let index = base + found.trailing_zeros() as usize;
+            found &= found - 1;
+            return Some(index);
+        }
+        if let Some((bytes, block_values)) = blocks.next() {
+            found = block(bytes, block_values);
+            base = next_base;
+            next_base += 16 / size;
+            continue;
+        }
+        while tail < values.len() {
+            tail += 1;
+            if matches(&values[tail - 1]) {
+                return Some(tail - 1);
+            }
+        }
+        return None;
+    })
+}
+
+// Range bounds repeated to fill a block, for comparing blocks of native order values as signed integers.
+// Unsigned values and bounds are biased (their sign bits flipped) to order the same way as signed ones.
+#[allow(dead_code)] // only used by the SIMD implementations
+pub(crate) struct LaneRange {
+    bias: [u8; 16],
+    start: Bound<[u8; 16]>,
+    end: Bound<[u8; 16]>,
+}
+
+impl LaneRange {
+    fn new<T: Primitive>(start: Bound<T>, end: Bound<T>) -> Self {
+        let size = core::mem::size_of::<T>();
+        let mut bias = [0; 16];
+        if !is_signed::<T>() {
+            // The most significant byte of each (little-endian) value
+            bias[size - 1..]
+                .iter_mut()
+                .step_by(size)
+                .for_each(|byte| *byte = 0x80);
+        }
+        let lanes = |value: T| {
+            let mut lanes = bias;
+            for lane in lanes.chunks_exact_mut(size) {
+                lane.iter_mut()
+                    .zip(value.to_ne_bytes())
+                    .for_each(|(lane, byte)| *lane ^= byte);
+            }
+            lanes
+        };
+        Self {
+            bias,
+            start: start.map(lanes),
+            end: end.map(lanes),
+        }
+    }
+}
+
+// Returns a bitmask of the values of `size` bytes in a 16-byte block within `range`, swapping them to
+// native order first if `swap` is set, or `None` to leave it to the scalar comparisons
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+fn range_block(block: &[u8], size: usize, swap: bool, range: &LaneRange) -> Option<u32> {
+    // SAFETY: SSE2 is enabled at compile time
+    (size <= 4).then(|| unsafe { x86::range_block_sse2(block, size, swap, range) })
+}
+
+// Returns a bitmask of the values of `size` bytes in a 16-byte block within `range`, swapping them to
+// native order first if `swap` is set, or `None` to leave it to the scalar comparisons
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+)))]
+fn range_block(_block: &[u8], _size: usize, _swap: bool, _range: &LaneRange) -> Option<u32> {
+    None
+}
+
+// Returns a bitmask of the values of `size` bytes in a 16-byte block equal to those in `needle`
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+fn eq_block(block: &[u8], needle: &[u8; 16], size: usize) -> u32 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__m128i, _mm_cmpeq_epi8, _mm_loadu_si128, _mm_movemask_epi8};
+
+    assert_eq!(block.len(), 16);
+    // SAFETY: SSE2 is enabled at compile time, and both blocks are 16 bytes
+    let bytes = unsafe {
+        let block = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let needle = _mm_loadu_si128(needle.as_ptr() as *const __m128i);
+        _mm_movemask_epi8(_mm_cmpeq_epi8(block, needle)) as u32
+    };
+    value_mask(bytes, size)
+}
+
+// Returns a bitmask of the values of `size` bytes in a 16-byte block equal to those in `needle`
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+)))]
+fn eq_block(block: &[u8], needle: &[u8; 16], size: usize) -> u32 {
+    let bytes = block
+        .iter()
+        .zip(needle)
+        .enumerate()
+        .fold(0, |bytes, (i, (a, b))| bytes | ((a == b) as u32) << i);
+    value_mask(bytes, size)
+}
+
+/// Reverses the bytes of each value in place, converting between big and little endian
+///
+/// Uses SIMD byte shuffles where available: SSSE3 on x86 (detected at run time with the `std` feature, otherwise
+/// only if enabled at compile time, falling back to SSE2), and NEON on AArch64.
+pub fn swap_slice_in_place<T: Primitive>(values: &mut [T]) {
+    if core::mem::size_of::<T>() > 1 {
+        let ptr = values.as_mut_ptr();
+        // SAFETY: the source and destination are the same slice
+        unsafe { swap_copy(ptr, ptr, values.len()) }
+    }
+}
+
+impl<T: Primitive, E: Endianness> Endian<T, E> {
+    /// Converts a slice of values to native values in place, returning the same memory as a native slice
+    pub fn slice_to_native(values: &mut [Self]) -> &mut [T] {
+        // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`
+        let values = unsafe { &mut *(values as *mut [Self] as *mut [T]) };
+        if E::ORDER != ByteOrder::NATIVE {
+            swap_slice_in_place(values);
+        }
+        values
+    }
+    /// Converts a slice of native values to this byte order in place, returning the same memory as a slice of values
+    pub fn slice_from_native(values: &mut [T]) -> &mut [Self] {
+        if E::ORDER != ByteOrder::NATIVE {
+            swap_slice_in_place(values);
+        }
+        // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`
+        unsafe { &mut *(values as *mut [T] as *mut [Self]) }
+    }
+    /// Copies values into a slice of native values, which must have the same length
+    ///
+    /// Uses SIMD byte shuffles where available, see `swap_slice_in_place`.
+    pub fn copy_to_native(dst: &mut [T], src: &[Self]) {
+        assert_eq!(
+            dst.len(),
+            src.len(),
+            "source and destination slices have different lengths"
+        );
+        if E::ORDER == ByteOrder::NATIVE || core::mem::size_of::<T>() == 1 {
+            // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`
+            dst.copy_from_slice(unsafe { &*(src as *const [Self] as *const [T]) });
+        } else {
+            // SAFETY: the slices have the same length, and can't overlap as `dst` is borrowed mutably
+            unsafe { swap_copy(src.as_ptr() as *const T, dst.as_mut_ptr(), dst.len()) }
+        }
+    }
+    /// Copies native values into a slice of values, which must have the same length
+    ///
+    /// Uses SIMD byte shuffles where available, see `swap_slice_in_place`.
+    pub fn copy_from_native(dst: &mut [Self], src: &[T]) {
+        // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`
+        let (dst, src) = unsafe {
+            (
+                &mut *(dst as *mut [Self] as *mut [T]),
+                &*(src as *const [T] as *const [Self]),
+            )
+        };
+        // Swapping is its own inverse, so this is the same operation in the other direction
+        Self::copy_to_native(dst, src);
+    }
+    /// Returns the sum of a slice of values
+    pub fn slice_sum(values: &[Self]) -> T
+    where
+        T: core::iter::Sum,
+    {
+        values.iter().map(|value| EndianInt::value(*value)).sum()
+    }
+    /// Returns the smallest of a slice of values, or `None` if it's empty
+    ///
+    /// Values are compared in storage form (see `Ord`), so only the result is converted.
+    pub fn slice_min(values: &[Self]) -> Option<T>
+    where
+        Self: Ord,
+    {
+        values.iter().min().map(|value| EndianInt::value(*value))
+    }
+    /// Returns the largest of a slice of values, or `None` if it's empty
+    ///
+    /// Values are compared in storage form (see `Ord`), so only the result is converted.
+    pub fn slice_max(values: &[Self]) -> Option<T>
+    where
+        Self: Ord,
+    {
+        values.iter().max().map(|value| EndianInt::value(*value))
+    }
+    /// Returns `true` if a slice of values contains a native value
+    ///
+    /// The native value is converted once, rather than converting each value in the slice.
+    pub fn slice_contains(values: &[Self], value: T) -> bool
+    where
+        Self: PartialEq,
+    {
+        values.contains(&EndianInt::new(value))
+    }
+    /// Binary searches a slice of values sorted in ascending order for a native value
+    ///
+    /// As `slice::binary_search`, with the native value converted once and values compared in storage form.
+    pub fn slice_binary_search(values: &[Self], value: T) -> Result<usize, usize>
+    where
+        Self: Ord,
+    {
+        values.binary_search(&EndianInt::new(value))
+    }
+    /// Returns the index of the first value for which `pred` (given the native value) returns `false`
+    ///
+    /// As `slice::partition_point`, the slice must be partitioned by `pred`.
+    pub fn slice_partition_point(values: &[Self], mut pred: impl FnMut(T) -> bool) -> usize {
+        values.partition_point(|value| pred(EndianInt::value(*value)))
+    }
+    /// Sorts a slice of values in ascending order, using `scratch` (at least as long) as working space
+    ///
+    /// Values of up to 4 bytes are radix sorted on their stored bytes (as `slice_radix_sort_by_key`), which is
+    /// faster than `sort_unstable`. Wider values take too many passes for that, so they're sorted with
+    /// `sort_unstable` instead, which compares unsigned values' stored bytes directly (see `benches/sort.rs`).
+    /// Panics if `scratch` is shorter than `values`.
+    pub fn slice_radix_sort(values: &mut [Self], scratch: &mut [Self])
+    where
+        Self: Ord,
+    {
+        assert!(scratch.len() >= values.len(), "scratch is too short");
+        if core::mem::size_of::<T>() > RADIX_MAX_KEY_BYTES {
+            // Equal values are identical, so the sort needn't be stable
+            return values.sort_unstable();
+        }
+        Self::slice_radix_sort_by_key(values, scratch, |value| *value)
+    }
+    /// Sorts a slice by a key stored in each item, using `scratch` (at least as long) as working space
+    ///
+    /// This is a stable radix sort on the stored bytes of the keys, so keys are only compared within parts of a
+    /// few items. Large slices are split on the most significant key byte first, so the remaining passes over
+    /// each part stay in cache. This is faster than a comparison sort for keys of up to 4 bytes; wider keys are
+    /// still sorted stably, but more slowly than by `sort_unstable_by_key`. Panics if `scratch` is shorter than
+    /// `items`.
+    pub fn slice_radix_sort_by_key<S: Copy>(
+        items: &mut [S],
+        scratch: &mut [S],
+        key: impl Fn(&S) -> Self,
+    ) where
+        Self: Ord,
+    {
+        let size = core::mem::size_of::<T>();
+        // The sign bit of signed integers is flipped to order them after the bytes
+        let sign = if is_signed::<T>() { 0x80 } else { 0 };
+        let scratch = &mut scratch[..items.len()];
+        let byte = |item: &S, level| {
+            let bytes = key(item);
+            match E::ORDER {
+                ByteOrder::Big if level == size - 1 => bytes.as_ref()[0] ^ sign,
+                ByteOrder::Big => bytes.as_ref()[size - 1 - level],
+                ByteOrder::Little if level == size - 1 => bytes.as_ref()[level] ^ sign,
+                ByteOrder::Little => bytes.as_ref()[level],
+            }
+        };
+        radix_sort(items, scratch, size, &byte, &|a: &S, b: &S| key(a) < key(b));
+    }
+    /// Returns an iterator over the indices of the values equal to a native value
+    ///
+    /// The native value is converted once, and compared with the stored bytes 16 bytes at a time
+    /// (using SSE2 where available), so the values aren't converted.
+    pub fn slice_find_eq(values: &[Self], value: T) -> impl Iterator<Item = usize> + '_
+    where
+        Self: Eq,
+    {
+        let size = core::mem::size_of::<T>();
+        let value: Self = EndianInt::new(value);
+        let mut needle = [0; 16];
+        for chunk in needle.chunks_exact_mut(size) {
+            chunk.copy_from_slice(value.as_ref());
+        }
+        block_search(
+            values,
+            move |block, _| eq_block(block, &needle, size),
+            move |other| *other == value,
+        )
+    }
+    /// Returns an iterator over the indices of the values within a range of native values
+    ///
+    /// Values of up to 4 bytes are compared 16 bytes at a time with SSE2 where available, swapping
+    /// each block in registers and comparing it with the bounds converted once. Otherwise each value is
+    /// compared in storage form (see `Ord`) with the bounds converted once.
+    pub fn slice_find_in_range(
+        values: &[Self],
+        range: impl RangeBounds<T>,
+    ) -> impl Iterator<Item = usize> + '_
+    where
+        Self: Ord,
+    {
+        let (start, end) = (range.start_bound().cloned(), range.end_bound().cloned());
+        let lanes = LaneRange::new(start, end);
+        let bounds: (Bound<Self>, Bound<Self>) =
+            (start.map(EndianInt::new), end.map(EndianInt::new));
+        let contains = move |value: &Self| bounds.contains(value);
+        let (size, swap) = (core::mem::size_of::<T>(), E::ORDER != ByteOrder::NATIVE);
+        block_search(
+            values,
+            move |block, block_values| {
+                range_block(block, size, swap, &lanes).unwrap_or_else(|| {
+                    block_values
+                        .iter()
+                        .enumerate()
+                        .fold(0, |found, (i, value)| found | (contains(value) as u32) << i)
+                })
+            },
+            contains,
+        )
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{ByteOrder, ByteSource, EndianReader, Endianness, Error, Primitive, BE, LE};
+
+impl ByteOrder {
+    /// Detects the byte order of a marker stored at the start of `bytes` (e.g. a byte-order mark or a
+    /// pcap magic), given its big-endian bytes
+    ///
+    /// Returns `Big` if the bytes match, `Little` if they match reversed, or `None` otherwise.
+    pub fn from_marker(bytes: &[u8], marker: &[u8]) -> Option<ByteOrder> {
+        let stored = bytes.get(..marker.len())?;
+        if stored == marker {
+            Some(ByteOrder::Big)
+        } else if stored.iter().eq(marker.iter().rev()) {
+            Some(ByteOrder::Little)
+        } else {
+            None
+        }
+    }
+
+    /// Detects the byte order declared by a file header
+    ///
+    /// This recognises TIFF (and BigTIFF), ELF, pcap, pcapng, compound files (CFB, as used by
+    /// legacy Office documents) and UTF-16 byte-order marks.
+    ///
+    /// ```
+    /// use storage_endian::ByteOrder;
+    ///
+    /// assert_eq!(ByteOrder::detect(b"MM\x00\x2a\x00\x00\x00\x08"), Some(ByteOrder::Big));
+    /// assert_eq!(ByteOrder::detect(b"\x7fELF\x02\x01\x01\x00"), Some(ByteOrder::Little));
+    /// assert_eq!(ByteOrder::detect(b"GIF89a"), None);
+    /// ```
+    pub fn detect(header: &[u8]) -> Option<ByteOrder> {
+        const CFB: [u8; 8] = [0xd0, 0xcf, 0x11, 0xe0, 0xa1, 0xb1, 0x1a, 0xe1];
+        match header {
+            [b'I', b'I', 42 | 43, 0, ..] => Some(ByteOrder::Little),
+            [b'M', b'M', 0, 42 | 43, ..] => Some(ByteOrder::Big),
+            [0x7f, b'E', b'L', b'F', _, 1, ..] => Some(ByteOrder::Little),
+            [0x7f, b'E', b'L', b'F', _, 2, ..] => Some(ByteOrder::Big),
+            // The byte order field is 0xfffe, stored in the file's byte order
+            _ if header.starts_with(&CFB) => Self::from_marker(header.get(28..)?, &[0xff, 0xfe]),
+            // Section header block, with its byte-order magic after the block type and length
+            [0x0a, 0x0d, 0x0d, 0x0a, ..] => {
+                Self::from_marker(header.get(8..)?, &[0x1a, 0x2b, 0x3c, 0x4d])
+            }
+            _ => Self::from_marker(header, &[0xa1, 0xb2, 0xc3, 0xd4])
+                .or_else(|| Self::from_marker(header, &[0xa1, 0xb2, 0x3c, 0x4d]))
+                .or_else(|| Self::from_marker(header, &[0xfe, 0xff])),
+        }
+    }
+}
+
+/// A cursor reading native values stored in a byte order selected at runtime
+///
+/// ```
+/// use storage_endian::DynamicReader;
+///
+/// fn first_ifd(tiff: &[u8]) -> Option<u32> {
+///     let mut reader = DynamicReader::detect(tiff)?;
+///     reader.get_mut().skip(2).ok()?;
+///     assert_eq!(reader.read::<u16>().ok()?, 42);
+///     reader.read::<u32>().ok()
+/// }
+///
+/// assert_eq!(first_ifd(b"MM\x00\x2a\x00\x00\x00\x08"), Some(8));
+/// assert_eq!(first_ifd(b"II\x2a\x00\x08\x00\x00\x00"), Some(8));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DynamicReader<S> {
+    reader: EndianReader<S>,
+    order: ByteOrder,
+}
+
+impl<'a> DynamicReader<&'a [u8]> {
+    /// Detects the byte order of a file header (see `ByteOrder::detect`), returning a reader starting at
+    /// the beginning of `bytes`
+    pub fn detect(bytes: &'a [u8]) -> Option<Self> {
+        Some(Self::new(
+            EndianReader::new(bytes),
+            ByteOrder::detect(bytes)?,
+        ))
+    }
+}
+
+impl<S: ByteSource> DynamicReader<S> {
+    /// Creates a reader of values stored in byte order `order`, starting at the current position of `reader`
+    pub fn new(reader: EndianReader<S>, order: ByteOrder) -> Self {
+        Self { reader, order }
+    }
+
+    /// Returns the byte order
+    pub fn order(&self) -> ByteOrder {
+        self.order
+    }
+
+    /// Changes the byte order of later reads
+    pub fn set_order(&mut self, order: ByteOrder) {
+        self.order = order;
+    }
+
+    /// Returns the underlying reader, e.g. to read raw bytes
+    pub fn get_mut(&mut self) -> &mut EndianReader<S> {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> EndianReader<S> {
+        self.reader
+    }
+
+    /// Reads a native value stored in the selected byte order, e.g. `reader.read::<u32>()`
+    ///
+    /// On error nothing is consumed, as for `EndianReader::read_into`.
+    pub fn read<T: Primitive>(&mut self) -> Result<T, Error> {
+        let mut bytes = T::Bytes::default();
+        self.reader.read_into(bytes.as_mut())?;
+        Ok(match self.order {
+            ByteOrder::Big => BE::from_bytes(bytes),
+            ByteOrder::Little => LE::from_bytes(bytes),
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{EndianValue, EndianView, EndianViewMut, Error};
+use core::marker::PhantomData;
+
+/// A field of type `V` at byte offset `OFFSET`, for describing a layout as named offsets and accessing
+/// individual fields without reading the whole structure
+///
+/// Accesses on fixed-size arrays are bounds-checked at compile time.
+///
+/// ```
+/// use storage_endian::{Field, LEu16, LEu32};
+///
+/// // Fields of a 64-bit little-endian ELF header
+/// type Machine = Field<LEu16, 18>;
+/// type Version = Field<LEu32, 20>;
+///
+/// let mut header = [0; 64];
+/// Machine::set(&mut header, LEu16::new(0x3e));
+/// assert_eq!(Machine::get(&header), 0x3e);
+/// assert_eq!(Version::read(&header[..22]).unwrap_err().offset(), Some(20));
+/// ```
+///
+/// Accessing a field which doesn't fit within an array doesn't compile:
+/// ```compile_fail
+/// use storage_endian::{Field, LEu32};
+///
+/// Field::<LEu32, 20>::get(&[0; 22]);
+/// ```
+pub struct Field<V, const OFFSET: usize>(PhantomData<V>);
+
+impl<V: EndianValue, const OFFSET: usize> Field<V, OFFSET> {
+    /// The offset of the field
+    pub const OFFSET: usize = OFFSET;
+    /// The size of the field
+    pub const SIZE: usize = core::mem::size_of::<V::Bytes>();
+    /// The offset just past the end of the field
+    pub const END: usize = OFFSET + Self::SIZE;
+
+    /// Reads the field from a buffer
+    pub fn read(bytes: &[u8]) -> Result<V, Error> {
+        EndianView::new(bytes).get(OFFSET)
+    }
+
+    /// Writes the field into a buffer
+    ///
+    /// On error nothing is written.
+    pub fn write(bytes: &mut [u8], value: V) -> Result<(), Error> {
+        EndianViewMut::new(bytes).set(OFFSET, value)
+    }
+
+    /// Reads the field from an array, which is checked to be large enough at compile time
+    pub fn get<const N: usize>(bytes: &[u8; N]) -> V {
+        const { assert!(Self::END <= N, "field out of bounds") };
+        let mut value = V::Bytes::default();
+        value.as_mut().copy_from_slice(&bytes[OFFSET..Self::END]);
+        V::from_storage(value)
+    }
+
+    /// Writes the field into an array, which is checked to be large enough at compile time
+    pub fn set<const N: usize>(bytes: &mut [u8; N], value: V) {
+        const { assert!(Self::END <= N, "field out of bounds") };
+        bytes[OFFSET..Self::END].copy_from_slice(value.to_storage().as_ref());
+    }
+}
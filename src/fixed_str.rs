@@ -0,0 +1,85 @@
+use core::fmt::{Debug, Display};
+use core::str::Utf8Error;
+
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+/// A fixed-length string field, padded with NUL bytes or spaces (e.g. tar or ISO 9660 header fields)
+pub struct FixedStr<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedStr<N> {
+    /// Creates a field from a string padded with NUL bytes, returning `None` if it's too long
+    pub const fn new(s: &str) -> Option<Self> {
+        Self::with_padding(s, 0)
+    }
+
+    /// Creates a field from a string padded with the given byte, returning `None` if it's too long
+    pub const fn with_padding(s: &str, pad: u8) -> Option<Self> {
+        let s = s.as_bytes();
+        if s.len() > N {
+            return None;
+        }
+        let mut bytes = [pad; N];
+        let mut i = 0;
+        while i < s.len() {
+            bytes[i] = s[i];
+            i += 1;
+        }
+        Some(Self(bytes))
+    }
+
+    /// Returns all bytes of the field, including any padding
+    pub const fn raw(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the bytes of the field up to the first NUL byte, with trailing spaces removed
+    pub fn trimmed(&self) -> &[u8] {
+        let bytes = match self.0.iter().position(|&b| b == 0) {
+            Some(len) => &self.0[..len],
+            None => &self.0[..],
+        };
+        let len = bytes.iter().rposition(|&b| b != b' ').map_or(0, |i| i + 1);
+        &bytes[..len]
+    }
+
+    /// Returns the field as a string without padding, or an error if it's not valid UTF-8
+    pub fn as_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self.trimmed())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedStr<N> {
+    fn from(other: [u8; N]) -> Self {
+        Self(other)
+    }
+}
+
+impl<const N: usize> PartialEq<str> for FixedStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.trimmed() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedStr<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.trimmed() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Display for FixedStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.as_str() {
+            Ok(s) => Display::fmt(s, f),
+            Err(_) => Display::fmt(&self.trimmed().escape_ascii(), f),
+        }
+    }
+}
+
+impl<const N: usize> Debug for FixedStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.as_str() {
+            Ok(s) => Debug::fmt(s, f),
+            Err(_) => write!(f, "b\"{}\"", self.trimmed().escape_ascii()),
+        }
+    }
+}
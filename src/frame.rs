@@ -0,0 +1,255 @@
+use crate::{EndianLength, EndianReader, EndianWriter, Error, ErrorKind};
+use core::marker::PhantomData;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// Framing of payloads with a length prefix of type `Len` (e.g. `BEu16` or `LEu32`)
+///
+/// ```
+/// use storage_endian::{BEu16, LengthPrefix};
+///
+/// let framing = LengthPrefix::<BEu16>::new();
+/// let mut buf = [0; 16];
+/// let len = framing.encode_into(&mut buf, b"hello")?;
+/// assert_eq!(&buf[..len], b"\x00\x05hello");
+/// assert_eq!(framing.decode(&buf[..len + 1])?, (&b"hello"[..], &b"\x00"[..]));
+///
+/// // Lengths which count the prefix as well, as in IPv4 and many proprietary protocols
+/// let framing = LengthPrefix::<BEu16>::including_prefix();
+/// assert_eq!(framing.decode(b"\x00\x04hi")?.0, b"hi");
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+pub struct LengthPrefix<Len> {
+    includes_prefix: bool,
+    _marker: PhantomData<Len>,
+}
+
+impl<Len: EndianLength> LengthPrefix<Len> {
+    /// The size of the length prefix
+    pub const PREFIX_LEN: usize = core::mem::size_of::<Len::Bytes>();
+
+    /// Creates a framing where the length is of the payload only
+    pub const fn new() -> Self {
+        Self {
+            includes_prefix: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a framing where the length includes the prefix itself
+    pub const fn including_prefix() -> Self {
+        Self {
+            includes_prefix: true,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the total length of the frame starting at `bytes`, or `None` if the prefix isn't complete yet
+    ///
+    /// This can be used to find out how many bytes to wait for before a frame can be decoded.
+    pub fn frame_len(&self, bytes: &[u8]) -> Result<Option<usize>, Error> {
+        let len = match EndianReader::new(bytes).read::<Len>() {
+            Ok(len) => len,
+            Err(_) => return Ok(None),
+        };
+        let out_of_range = Error::new(ErrorKind::OutOfRange).at(0);
+        let len = len.to_len().ok_or(out_of_range)?;
+        if !self.includes_prefix {
+            return len
+                .checked_add(Self::PREFIX_LEN)
+                .map(Some)
+                .ok_or(out_of_range);
+        }
+        if len < Self::PREFIX_LEN {
+            return Err(out_of_range.values(Self::PREFIX_LEN as u64, len as u64));
+        }
+        Ok(Some(len))
+    }
+
+    /// Decodes the frame at the start of `bytes`, returning its payload and the bytes after it
+    pub fn decode<'a>(&self, bytes: &'a [u8]) -> Result<(&'a [u8], &'a [u8]), Error> {
+        let truncated = |needed: usize| {
+            Error::new(ErrorKind::Truncated)
+                .at(0)
+                .values(needed as u64, bytes.len() as u64)
+        };
+        let len = self.frame_len(bytes)?.ok_or(truncated(Self::PREFIX_LEN))?;
+        if bytes.len() < len {
+            return Err(truncated(len));
+        }
+        let (frame, rest) = bytes.split_at(len);
+        Ok((&frame[Self::PREFIX_LEN..], rest))
+    }
+
+    /// Encodes a frame for `payload` at the start of `buf`, returning the total length of the frame
+    pub fn encode_into(&self, buf: &mut [u8], payload: &[u8]) -> Result<usize, Error> {
+        let mut writer = EndianWriter::new(buf);
+        writer.write_frame(self, payload)?;
+        Ok(writer.position())
+    }
+}
+
+/// A way of finding where each frame in a byte stream ends, for use with `FrameParser`
+///
+/// This is implemented by `LengthPrefix`, and by closures for other formats (e.g. reading a length from a
+/// header `Field`).
+pub trait Framing {
+    /// Returns the total length of the frame starting at `bytes`, or `None` if more bytes are needed to know
+    fn frame_len(&self, bytes: &[u8]) -> Result<Option<usize>, Error>;
+}
+
+impl<Len: EndianLength> Framing for LengthPrefix<Len> {
+    fn frame_len(&self, bytes: &[u8]) -> Result<Option<usize>, Error> {
+        LengthPrefix::frame_len(self, bytes)
+    }
+}
+
+impl<F: Fn(&[u8]) -> Result<Option<usize>, Error>> Framing for F {
+    fn frame_len(&self, bytes: &[u8]) -> Result<Option<usize>, Error> {
+        self(bytes)
+    }
+}
+
+/// A push-based parser which accumulates chunks of a byte stream (e.g. as they arrive from a socket)
+/// in a fixed buffer, and returns each frame once it's complete
+///
+/// ```
+/// use storage_endian::{BEu16, FrameParser, LengthPrefix};
+///
+/// let framing = LengthPrefix::<BEu16>::new();
+/// let mut buf = [0; 64];
+/// let mut parser = FrameParser::new(framing, &mut buf);
+/// let mut frames = 0;
+/// for chunk in [&b"\x00\x02h"[..], b"i\x00", b"\x01!\x00"] {
+///     assert_eq!(parser.push(chunk), chunk.len());
+///     while let Some(frame) = parser.next_frame()? {
+///         let (payload, _) = framing.decode(frame)?;
+///         assert_eq!(payload, [&b"hi"[..], b"!"][frames]);
+///         frames += 1;
+///     }
+/// }
+/// assert_eq!((frames, parser.buffered()), (2, 1));
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct FrameParser<'a, F> {
+    framing: F,
+    buf: &'a mut [u8],
+    start: usize,
+    end: usize,
+}
+
+impl<'a, F: Framing> FrameParser<'a, F> {
+    /// Creates a parser which buffers incomplete frames in `buf`, which limits the maximum frame length
+    pub fn new(framing: F, buf: &'a mut [u8]) -> Self {
+        Self {
+            framing,
+            buf,
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// Returns the number of bytes buffered which aren't part of a returned frame
+    pub fn buffered(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns the framing
+    pub fn framing(&self) -> &F {
+        &self.framing
+    }
+
+    /// Appends bytes from the stream, returning how many were accepted
+    ///
+    /// Fewer bytes than given are accepted when the buffer is full, in which case the rest should be pushed
+    /// again after taking frames with `next_frame`.
+    pub fn push(&mut self, bytes: &[u8]) -> usize {
+        if self.start > 0 && self.buf.len() - self.end < bytes.len() {
+            // Move the partial frame to the start to make room
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+        }
+        let len = bytes.len().min(self.buf.len() - self.end);
+        self.buf[self.end..self.end + len].copy_from_slice(&bytes[..len]);
+        self.end += len;
+        len
+    }
+
+    /// Returns the next complete frame, or `None` if more bytes are needed
+    ///
+    /// Returns an `OutOfRange` error for a frame which is empty or too long for the buffer, which can't
+    /// be recovered from as the position of the next frame is unknown.
+    pub fn next_frame(&mut self) -> Result<Option<&[u8]>, Error> {
+        let pending = &self.buf[self.start..self.end];
+        let len = match self.framing.frame_len(pending)? {
+            Some(len) => len,
+            None => return Ok(None),
+        };
+        if len == 0 || len > self.buf.len() {
+            let error = Error::new(ErrorKind::OutOfRange).values(self.buf.len() as u64, len as u64);
+            return Err(error);
+        }
+        if len > pending.len() {
+            return Ok(None);
+        }
+        let frame = self.start..self.start + len;
+        self.start += len;
+        if self.start == self.end {
+            self.start = 0;
+            self.end = 0;
+        }
+        Ok(Some(&self.buf[frame]))
+    }
+}
+
+impl<Len: EndianLength> Default for LengthPrefix<Len> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> EndianReader<&'a [u8]> {
+    /// Reads a length-prefixed frame, returning its payload
+    ///
+    /// On error nothing is consumed.
+    pub fn read_frame<Len: EndianLength>(
+        &mut self,
+        framing: &LengthPrefix<Len>,
+    ) -> Result<&'a [u8], Error> {
+        let offset = self.position();
+        let (payload, _) = framing
+            .decode(self.remaining_bytes())
+            .map_err(|e| e.at(offset + e.offset().unwrap_or(0)))?;
+        self.skip(LengthPrefix::<Len>::PREFIX_LEN + payload.len())?;
+        Ok(payload)
+    }
+}
+
+impl EndianWriter<'_> {
+    /// Writes a length-prefixed frame containing `payload`
+    ///
+    /// On error nothing is written.
+    pub fn write_frame<Len: EndianLength>(
+        &mut self,
+        framing: &LengthPrefix<Len>,
+        payload: &[u8],
+    ) -> Result<(), Error> {
+        let prefix_len = LengthPrefix::<Len>::PREFIX_LEN;
+        let len = if framing.includes_prefix {
+            payload.len().checked_add(prefix_len)
+        } else {
+            Some(payload.len())
+        };
+        let out_of_range = Error::new(ErrorKind::OutOfRange).at(self.position());
+        let len = len.and_then(Len::from_len).ok_or(out_of_range)?;
+        let needed = prefix_len + payload.len();
+        if self.remaining() < needed {
+            let error = Error::new(ErrorKind::Truncated).at(self.position());
+            return Err(error.values(needed as u64, self.remaining() as u64));
+        }
+        self.write(len)?;
+        self.write_bytes(payload)?;
+        Ok(())
+    }
+}
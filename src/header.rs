@@ -0,0 +1,91 @@
+use crate::{
+    Endian, EndianReader, Endianness, Error, ErrorKind, FixedStr, Guid, MacAddr, Magic, Primitive,
+    Unaligned, Uuid,
+};
+
+/// A type which can be viewed in place in a byte buffer, for zero-copy parsing of protocol headers
+///
+/// Headers are peeled off a buffer one layer at a time with `split_header` or `EndianReader::read_header`,
+/// each returning a reference into the buffer along with the payload that follows:
+/// ```
+/// use storage_endian::{split_header, BEu16, EndianReader, Header, MacAddr, Unaligned};
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone)]
+/// struct Ethernet {
+///     dst: MacAddr,
+///     src: MacAddr,
+///     ether_type: Unaligned<BEu16>,
+/// }
+/// // Safety: a `repr(C)` struct of `Header` fields with no padding
+/// unsafe impl Header for Ethernet {}
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone)]
+/// struct Udp {
+///     src_port: Unaligned<BEu16>,
+///     dst_port: Unaligned<BEu16>,
+///     len: Unaligned<BEu16>,
+///     checksum: Unaligned<BEu16>,
+/// }
+/// unsafe impl Header for Udp {}
+///
+/// let frame = b"\xff\xff\xff\xff\xff\xff\x02\x00\x00\x00\x00\x01\x88\xb5\x00\x35\x04\x00\x00\x0a\x00\x00hi";
+/// let (ethernet, payload) = split_header::<Ethernet>(frame)?;
+/// assert_eq!(ethernet.dst, MacAddr::BROADCAST);
+/// assert_eq!(ethernet.ether_type.get(), 0x88b5);
+///
+/// let mut reader = EndianReader::new(payload);
+/// let udp = reader.read_header::<Udp>()?;
+/// assert_eq!(udp.dst_port.get(), 1024);
+/// assert_eq!(reader.remaining_bytes(), b"hi");
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+///
+/// Fields wider than a byte must be `Unaligned` if the header can start at any offset, otherwise viewing it
+/// fails with `ErrorKind::Misaligned` unless the buffer happens to be aligned.
+///
+/// # Safety
+/// The type must have no padding bytes and no interior mutability, and every bit pattern must be a valid value.
+/// This holds for a `#[repr(C)]` or `#[repr(transparent)]` struct whose fields all implement `Header`, and
+/// which has no padding between them.
+pub unsafe trait Header: Copy {}
+
+// Safety: these are all integers or arrays of integers, with no padding and no invalid bit patterns
+unsafe impl Header for u8 {}
+unsafe impl Header for i8 {}
+unsafe impl<T: Primitive, E: Endianness> Header for Endian<T, E> {}
+unsafe impl<H: Header, const N: usize> Header for [H; N] {}
+unsafe impl<H: Header> Header for Unaligned<H> {}
+unsafe impl Header for MacAddr {}
+unsafe impl Header for Uuid {}
+unsafe impl Header for Guid {}
+unsafe impl<const N: usize> Header for Magic<N> {}
+unsafe impl<const N: usize> Header for FixedStr<N> {}
+
+/// Views the start of `bytes` as a header in place, returning it and the bytes after it
+pub fn split_header<H: Header>(bytes: &[u8]) -> Result<(&H, &[u8]), Error> {
+    let size = core::mem::size_of::<H>();
+    if bytes.len() < size {
+        let error = Error::new(ErrorKind::Truncated).at(0);
+        return Err(error.values(size as u64, bytes.len() as u64));
+    }
+    if bytes.as_ptr() as usize % core::mem::align_of::<H>() != 0 {
+        return Err(Error::new(ErrorKind::Misaligned).at(0));
+    }
+    // Safety: the size and alignment were checked, and `Header` guarantees any bytes are a valid value
+    let header = unsafe { &*(bytes.as_ptr() as *const H) };
+    Ok((header, &bytes[size..]))
+}
+
+impl<'a> EndianReader<&'a [u8]> {
+    /// Views the next bytes as a header in place, returning a reference into the buffer
+    ///
+    /// On error nothing is consumed.
+    pub fn read_header<H: Header>(&mut self) -> Result<&'a H, Error> {
+        let offset = self.position();
+        let (header, _) = split_header::<H>(self.remaining_bytes()).map_err(|e| e.at(offset))?;
+        self.skip(core::mem::size_of::<H>())?;
+        Ok(header)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::EndianValue;
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+/// Extension methods reading values from any `Read`
+///
+/// ```
+/// use storage_endian::{BEu64, ReadEndian};
+///
+/// let mut reader: &[u8] = &[0, 0, 0, 0, 0, 0, 1, 0, 0xff];
+/// assert_eq!(reader.read_value::<BEu64>()?, 256);
+/// assert_eq!(reader.read_value::<u8>()?, 0xff);
+/// assert!(reader.read_value::<u8>().is_err());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait ReadEndian: Read {
+    /// Reads a value from its storage order bytes, e.g. `reader.read_value::<BEu64>()`
+    fn read_value<V: EndianValue>(&mut self) -> Result<V> {
+        let mut bytes = V::Bytes::default();
+        self.read_exact(bytes.as_mut())?;
+        Ok(V::from_storage(bytes))
+    }
+
+    /// Reads a value from its storage order bytes at `offset` from the start of a seekable stream
+    ///
+    /// The stream position is restored afterwards, even if the read fails.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use storage_endian::{LEu16, ReadEndian};
+    ///
+    /// let mut file = Cursor::new(b"\x01\x02\x03\x04");
+    /// assert_eq!(file.read_value_at::<LEu16>(2)?, 0x0403);
+    /// assert_eq!(file.position(), 0);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    fn read_value_at<V: EndianValue>(&mut self, offset: u64) -> Result<V>
+    where
+        Self: Seek,
+    {
+        let position = self.stream_position()?;
+        self.seek(SeekFrom::Start(offset))?;
+        let value = self.read_value();
+        self.seek(SeekFrom::Start(position))?;
+        value
+    }
+}
+
+impl<R: Read + ?Sized> ReadEndian for R {}
+
+/// Extension methods reading values at an offset within a file, without using its cursor
+///
+/// This uses `pread` on unix, and `ReadFile` with an offset on windows (which does move the cursor).
+/// As it only needs `&File`, a file can be probed from several threads at once.
+#[cfg(any(unix, windows))]
+pub trait FileReadEndian {
+    /// Reads exactly `buf.len()` bytes at `offset`
+    fn read_exact_at_offset(&self, buf: &mut [u8], offset: u64) -> Result<()>;
+
+    /// Reads a value from its storage order bytes at `offset`, e.g. `file.pread_value::<BEu32>(1024)`
+    fn pread_value<V: EndianValue>(&self, offset: u64) -> Result<V> {
+        let mut bytes = V::Bytes::default();
+        self.read_exact_at_offset(bytes.as_mut(), offset)?;
+        Ok(V::from_storage(bytes))
+    }
+}
+
+#[cfg(unix)]
+impl FileReadEndian for std::fs::File {
+    fn read_exact_at_offset(&self, buf: &mut [u8], offset: u64) -> Result<()> {
+        std::os::unix::fs::FileExt::read_exact_at(self, buf, offset)
+    }
+}
+
+#[cfg(windows)]
+impl FileReadEndian for std::fs::File {
+    fn read_exact_at_offset(&self, mut buf: &mut [u8], mut offset: u64) -> Result<()> {
+        while !buf.is_empty() {
+            match std::os::windows::fs::FileExt::seek_read(self, buf, offset) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(len) => {
+                    buf = &mut buf[len..];
+                    offset += len as u64;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Extension methods writing values to any `Write`
+///
+/// ```
+/// use storage_endian::{LEu32, WriteEndian};
+///
+/// let mut writer = Vec::new();
+/// writer.write_value(LEu32::from(7))?;
+/// assert_eq!(writer, [7, 0, 0, 0]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub trait WriteEndian: Write {
+    /// Writes the storage order bytes of a value
+    fn write_value<V: EndianValue>(&mut self, value: V) -> Result<()> {
+        self.write_all(value.to_storage().as_ref())
+    }
+}
+
+impl<W: Write + ?Sized> WriteEndian for W {}
+
+impl From<crate::Error> for Error {
+    fn from(other: crate::Error) -> Self {
+        match other.kind() {
+            crate::ErrorKind::Truncated => Error::new(ErrorKind::UnexpectedEof, other),
+            _ => Error::new(ErrorKind::InvalidData, other),
+        }
+    }
+}
@@ -32,6 +32,10 @@
 /*!
     Simple integer wrappers for explicitly defining storage endianess.
 
+    Floating point values (`f32` and `f64`) are also supported, swapping the bytes of their bit pattern.
+    Odd-width integers (`BEu24`, `LEi48`, etc.) are stored as byte arrays and convert to and from the next largest native type,
+    with signed values being sign extended. Any width from 1 to 16 bytes can be described with `BigEndian<UInt<N>>` or `BigEndian<Int<N>>`.
+
     The wrappers provide comparison, arithmetic, and conversion using standard Rust traits.
 
     I've ended up writing bits of this crate several times on various projects, so I decided it's time to make it a crate! :)
@@ -39,7 +43,7 @@
     Example Usage
     -------------
     ```rust
-    use storage_endian::{BEu32, BEu64};
+    use storage_endian::{BEu32, BEu64, Error, ErrorKind};
 
     #[repr(C)]
     struct Data {
@@ -56,15 +60,23 @@
             // ...
         }
 
-        pub fn from_bytes(data: [u8; Self::SIZE]) -> Self {
-            let mut data: Self = unsafe { core::mem::transmute(data) };
+        pub fn from_bytes(data: [u8; Self::SIZE]) -> Result<Self, Error> {
+            let data: Self = unsafe { core::mem::transmute(data) };
 
-            assert_eq!(data.magic, Self::MAGIC);
-            assert_eq!((data.version >> 16) & 0xff, 0x01);
-            assert!(data.size >= Self::SIZE as u64);
+            if data.magic != Self::MAGIC {
+                let error = Error::new(ErrorKind::BadMagic).at(0);
+                return Err(error.values(Self::MAGIC.into(), data.magic.value().into()));
+            }
+            if (data.version >> 16) & 0xff != 0x01 {
+                return Err(Error::new(ErrorKind::OutOfRange).at(4));
+            }
+            if data.size < Self::SIZE as u64 {
+                let error = Error::new(ErrorKind::Truncated).at(8);
+                return Err(error.values(Self::SIZE as u64, data.size.into()));
+            }
             Self::handle_thing(data.thing.into());
 
-            data
+            Ok(data)
         }
     }
     ```
@@ -72,6 +84,42 @@
     As you can see, most of the time you don't have to worry what endianess the underlying data is, the operator overloading handles it for you.
 
     To avoid bugs, there is intentionally no easy way to access the data in the underlying representation.
+    If you really need it, the `raw-access` feature enables `to_raw` and `from_raw` methods.
+
+    The `alloc` feature enables conversions between `Vec`s of values, native values and bytes.
+    The `std` feature (which implies `alloc`) adds `ReadEndian` and `WriteEndian` extension traits
+    for reading and writing values with any `std::io::Read` or `Write`.
+    The `half` feature adds wrappers of the `half` crate's 16-bit floats, such as `BEf16` and `LEbf16`.
+
+    Individual values can also be built safely from their storage order bytes, and compared, even in `const` context:
+    ```rust
+    use storage_endian::BEu32;
+
+    const MAGIC: BEu32 = BEu32::from_bytes([0x13, 0x37, 0xbe, 0xef]);
+    assert_eq!(MAGIC, 0x1337_beef);
+    const _: () = assert!(MAGIC.const_eq_value(0x1337_beef));
+    ```
+
+    Wrapper values can't be used as `match` patterns, as their equality isn't structural (floats compare by value).
+    Match on the native value instead, against native constants:
+    ```rust
+    use storage_endian::{be, BEu16};
+
+    const RECORD_A: u16 = 1;
+    const RECORD_B: u16 = 2;
+
+    fn handle(record_type: BEu16) -> &'static str {
+        match record_type.value() {
+            RECORD_A => "a",
+            RECORD_B => "b",
+            _ => "unknown",
+        }
+    }
+    assert_eq!(handle(be!(RECORD_B)), "b");
+    ```
+
+    `BigEndian<T>`, `LittleEndian<T>` and `NativeEndian<T>` are aliases of `Endian<T, E>` with the `BE`, `LE` and `NE` markers.
+    Structures and parsers that handle both byte orders can be written once, generic over `E: Endianness`.
 
     Alternatives
     ------------
@@ -87,132 +135,2969 @@
 #![no_std]
 
 use core::cmp::Ordering;
+use core::convert::TryFrom;
 use core::fmt::{Binary, Debug, Display, LowerExp, LowerHex, Octal, Pointer, UpperExp, UpperHex};
+use core::marker::PhantomData;
+use core::net::{Ipv4Addr, Ipv6Addr};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
+use core::time::Duration;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 
 macro_rules! wrapper {
-    // Implement various traits needed for the endian wrappers
-    ( $( $Wrapper:ident :: $fn:ident  ),* ) => { $(
-        wrapper!(derive IntFrom(usize::$fn, u128::$fn, u64::$fn, u32::$fn, u16::$fn, u8::$fn,) for $Wrapper);
-        wrapper!(derive IntFrom(isize::$fn, i128::$fn, i64::$fn, i32::$fn, i16::$fn, i8::$fn,) for $Wrapper);
-        wrapper!(derive Fmt(Debug::fmt, Display::fmt, LowerExp::fmt, Pointer::fmt, UpperExp::fmt, ) for $Wrapper);
-        wrapper!(derive Fmt(Binary::fmt, LowerHex::fmt, Octal::fmt, UpperHex::fmt,) for $Wrapper);
-        wrapper!(derive Math(Add::add, Div::div, Mul::mul, Rem:: rem, Sub::sub,) for $Wrapper);
-        wrapper!(derive Math(BitAnd::bitand, BitOr::bitor, BitXor::bitxor, Shl::shl, Shr::shr,) for $Wrapper);
-
-        impl<T: Copy + From<$Wrapper<T>> + PartialEq> PartialEq<T> for $Wrapper<T> {
+    // Implement the inherent `const` methods of each byte order, along with the traits depending on them.
+    // Everything else is implemented once, generic over the byte order (see `derive Generic for Endian{E}`).
+    ( $( $Wrapper:ident($to:ident, $from:ident) ),* ) => { $(
+        wrapper!(derive From(usize, u64, u32, u16, isize, i64, i32, i16, f32, f64) via $to/$from for $Wrapper);
+        wrapper!(derive Byte(u8, i8) for $Wrapper);
+        wrapper!(derive Wide(u128, i128) via $to/$from for $Wrapper);
+        wrapper!(derive Bytes(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8, f32, f64,) for $Wrapper);
+        #[cfg(feature = "half")]
+        wrapper!(derive Half(f16, bf16) via $to/$from for $Wrapper);
+        #[cfg(feature = "half")]
+        wrapper!(derive Bytes(f16, bf16,) for $Wrapper);
+        wrapper!(derive NonZero(NonZeroUsize as usize, NonZeroU128 as u128, NonZeroU64 as u64, NonZeroU32 as u32, NonZeroU16 as u16, NonZeroU8 as u8) via $to/$from for $Wrapper);
+        wrapper!(derive NonZero(NonZeroIsize as isize, NonZeroI128 as i128, NonZeroI64 as i64, NonZeroI32 as i32, NonZeroI16 as i16, NonZeroI8 as i8) via $to/$from for $Wrapper);
+        wrapper!(derive Bool(u64, u32, u16, u8) via $to/$from for $Wrapper);
+        wrapper!(derive Char via $to/$from for $Wrapper);
+        wrapper!(derive Bcd(u128, u64, u32, u16, u8) via $to/$from for $Wrapper);
+        wrapper!(derive Array(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8, f64, f32) via $to/$from for $Wrapper);
+        wrapper!(derive Signed OnesComplement(i128, i64, i32, i16, i8) via $to/$from for $Wrapper);
+        wrapper!(derive Signed SignMagnitude(i128, i64, i32, i16, i8) via $to/$from for $Wrapper);
+        wrapper!(derive Sentinel(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8) via $to/$from for $Wrapper);
+        wrapper!(derive Overflow Wrapping(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8) via $to/$from for $Wrapper);
+        wrapper!(derive Overflow Saturating(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8) via $to/$from for $Wrapper);
+        wrapper!(derive UnixTime(u64, u32, i64, i32) via $to/$from for $Wrapper);
+        wrapper!(derive Fixed(u64 as u128, u32 as u64, u16 as u32, u8 as u16, i64 as i128, i32 as i64, i16 as i32, i8 as i16) via $to/$from for $Wrapper);
+        wrapper!(derive ByteInt UInt<1 as u8, 2 as u16, 3 as u32, 4 as u32, 5 as u64, 6 as u64, 7 as u64, 8 as u64, 9 as u128, 10 as u128, 11 as u128, 12 as u128, 13 as u128, 14 as u128, 15 as u128, 16 as u128> via $to/$from for $Wrapper);
+        wrapper!(derive ByteInt Int<1 as i8, 2 as i16, 3 as i32, 4 as i32, 5 as i64, 6 as i64, 7 as i64, 8 as i64, 9 as i128, 10 as i128, 11 as i128, 12 as i128, 13 as i128, 14 as i128, 15 as i128, 16 as i128> via $to/$from for $Wrapper);
+    )* };
+
+
+    // Implement the traits and methods shared by all inner types of a wrapper
+    ( derive Generic for $Wrapper:ident{$($E:ident)?} ) => {
+        wrapper!(derive Enum for $Wrapper{$($E)?});
+        wrapper!(derive Fmt(Debug::fmt, Display::fmt, LowerExp::fmt, Pointer::fmt, UpperExp::fmt, ) for $Wrapper{$($E)?});
+        wrapper!(derive Fmt(Binary::fmt, LowerHex::fmt, Octal::fmt, UpperHex::fmt,) for $Wrapper{$($E)?});
+        wrapper!(derive Math(Add::add, Div::div, Mul::mul, Rem:: rem, Sub::sub,) for $Wrapper{$($E)?});
+        wrapper!(derive Bitwise(BitAnd::bitand, BitOr::bitor, BitXor::bitxor,) for $Wrapper{$($E)?});
+        wrapper!(derive Math(Shl::shl, Shr::shr,) for $Wrapper{$($E)?});
+
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + PartialEq> PartialEq<T> for $Wrapper<T $(, $E)?> {
+            #[inline(always)]
             fn eq(&self, other: &T) -> bool {
                 T::eq(&T::from(*self), other)
             }
         }
-        impl<T: Copy + From<$Wrapper<T>> + PartialEq> PartialEq for $Wrapper<T> {
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + PartialEq + private::StorageCmp> PartialEq for $Wrapper<T $(, $E)?> {
+            #[inline(always)]
             fn eq(&self, other: &Self) -> bool {
-                T::eq(&T::from(*self), &T::from(*other))
+                if T::RAW_EQ {
+                    // Same-width values are equal exactly when their stored representations are
+                    T::eq(&self.0, &other.0)
+                } else {
+                    T::eq(&T::from(*self), &T::from(*other))
+                }
             }
         }
-        impl<T: Copy + From<$Wrapper<T>> + PartialOrd> PartialOrd<T> for $Wrapper<T> {
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + PartialOrd> PartialOrd<T> for $Wrapper<T $(, $E)?> {
+            #[inline(always)]
             fn partial_cmp(&self, other: &T) -> Option<Ordering> {
                 T::partial_cmp(&T::from(*self), other)
             }
         }
-        impl<T: Copy + From<$Wrapper<T>> + PartialOrd> PartialOrd for $Wrapper<T> {
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + PartialOrd + private::StorageCmp> PartialOrd for $Wrapper<T $(, $E)?> {
+            #[inline(always)]
             fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                $(
+                    if T::RAW_ORD && $E::ORDER != ByteOrder::NATIVE {
+                        return Some(private::cmp_stored(&self.0, &other.0, $E::ORDER));
+                    }
+                )?
                 T::partial_cmp(&T::from(*self), &T::from(*other))
             }
         }
-        impl<T: Copy + From<$Wrapper<T>> + Ord> Ord for $Wrapper<T> {
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Ord + private::StorageCmp> Ord for $Wrapper<T $(, $E)?> {
+            #[inline(always)]
             fn cmp(&self, other: &Self) -> Ordering {
+                $(
+                    // Unsigned values order like their bytes, most significant first, so there's no
+                    // need to swap them (the native order is converted for free instead)
+                    if T::RAW_ORD && $E::ORDER != ByteOrder::NATIVE {
+                        return private::cmp_stored(&self.0, &other.0, $E::ORDER);
+                    }
+                )?
                 T::cmp(&T::from(*self), &T::from(*other))
             }
         }
-        impl<T: Copy + From<$Wrapper<T>> + Eq> Eq for $Wrapper<T> {}
-    )* };
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Eq + private::StorageCmp> Eq for $Wrapper<T $(, $E)?> {}
+
+        #[cfg(feature = "raw-access")]
+        impl<$($E: Endianness,)? T: Copy> $Wrapper<T $(, $E)?> {
+            /// Returns the underlying storage representation, without any byte swapping
+            #[inline(always)]
+            pub const fn to_raw(self) -> T {
+                self.0
+            }
+            /// Creates a value from the underlying storage representation, without any byte swapping
+            #[inline(always)]
+            pub const fn from_raw(raw: T) -> Self {
+                Self::wrap(raw)
+            }
+        }
+
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Into<$Wrapper<T $(, $E)?>>> $Wrapper<T $(, $E)?> {
+            /// Returns the native value
+            #[inline(always)]
+            pub fn get(self) -> T {
+                T::from(self)
+            }
+            /// Stores a native value
+            #[inline(always)]
+            pub fn set(&mut self, value: T) {
+                *self = value.into();
+            }
+            /// Updates the stored value using a function on the native value
+            pub fn update(&mut self, f: impl FnOnce(T) -> T) {
+                self.set(f(self.get()));
+            }
+            /// Returns a new value computed by a function on the native value
+            ///
+            /// Longer expressions can be evaluated in native form this way, only converting at either end.
+            /// Optimised builds already cancel the intermediate conversions of chained operators (see
+            /// `benches/chain.rs`), so this mostly speeds up unoptimised builds.
+            #[inline(always)]
+            pub fn map(self, f: impl FnOnce(T) -> T) -> Self {
+                f(self.get()).into()
+            }
+            /// Stores a native value, returning the previous native value
+            pub fn replace(&mut self, value: T) -> T {
+                let old = self.get();
+                self.set(value);
+                old
+            }
+            /// Returns the native value, leaving the default (zero) value in its place
+            pub fn take(&mut self) -> T
+            where
+                T: Default,
+            {
+                self.replace(T::default())
+            }
+        }
+
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Into<$Wrapper<T $(, $E)?>> + Ord> $Wrapper<T $(, $E)?> {
+            /// Returns the minimum of two values, accepting either a native or wrapped value
+            pub fn min(self, other: impl Into<T>) -> Self {
+                T::min(T::from(self), other.into()).into()
+            }
+            /// Returns the maximum of two values, accepting either a native or wrapped value
+            pub fn max(self, other: impl Into<T>) -> Self {
+                T::max(T::from(self), other.into()).into()
+            }
+            /// Restricts the value to a certain interval, panicking if `min > max`
+            pub fn clamp(self, min: impl Into<T>, max: impl Into<T>) -> Self {
+                T::clamp(T::from(self), min.into(), max.into()).into()
+            }
+        }
+    };
 
 
     // Expand `derive Foo(a, b,) for Bar` into `derive Foo a for Bar`, `derive Foo b for Bar`
     ( derive $kind:ident($( $trait:ident :: $fn:ident , )*) for $Wrapper:ident ) => {
         $( wrapper!{ derive $kind $trait :: $fn for $Wrapper } )*
     };
+    ( derive $kind:ident($( $t:ident , )*) for $Wrapper:ident ) => {
+        $( wrapper!{ derive $kind $t for $Wrapper } )*
+    };
+    ( derive $kind:ident($( $trait:ident :: $fn:ident , )*) for $Wrapper:ident $g:tt ) => {
+        $( wrapper!{ derive $kind $trait :: $fn for $Wrapper $g } )*
+    };
+    ( derive $kind:ident($( $t:ident , )*) for $Wrapper:ident $g:tt ) => {
+        $( wrapper!{ derive $kind $t for $Wrapper $g } )*
+    };
 
-    // Implement bi-directional `From` for a type supporting `to_be`
-    ( derive IntFrom $t:ident :: $fn:ident for $Wrapper:ident ) => {
-        impl From<$Wrapper<$t>> for $t {
-            fn from(other: $Wrapper<$t>) -> $t {
-                <$t>::$fn(other.0)
+    // Implement bi-directional `From` for types supporting `to_be_bytes`/`from_be_bytes`
+    ( derive From($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$t> {
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                Self::wrap(<$t>::from_ne_bytes(value.$to()))
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                <$t>::$from(self.0.to_ne_bytes())
             }
         }
-        impl From<$t> for $Wrapper<$t> {
-            fn from(other: $t) -> Self {
-                Self(<$t>::$fn(other))
+        wrapper!(derive Const($t) for $Wrapper);
+    )* };
+
+    // Implement `new`/`value` for single byte types, which are stored as is in every byte order
+    ( derive Byte($( $t:ident ),*) for $Wrapper:ident ) => { $(
+        impl $Wrapper<$t> {
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                Self::wrap(value)
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                self.0
             }
         }
-    };
+        wrapper!(derive Const($t) for $Wrapper);
+    )* };
 
-    // Implement a formatting trait for a wrapper type
-    ( derive Fmt $Trait:ident :: $fn:ident for $Wrapper:ident ) => {
-        impl<T: Copy + From<$Wrapper<T>> + $Trait> $Trait for $Wrapper<T> {
-            fn $fn(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-                $Trait::$fn(&T::from(*self), f)
+    // Implement `new`/`value` for 128-bit types using two 64-bit swaps (see `wide`)
+    ( derive Wide($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$t> {
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                Self::wrap(<$t>::from_ne_bytes(wide::$to(value as u128)))
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                wide::$from(self.0.to_ne_bytes()) as $t
+            }
+        }
+        wrapper!(derive Const($t) for $Wrapper);
+    )* };
+
+    // Implement `new`/`value` for the `half` crate's floats, whose comparisons (and so `derive Const`) aren't `const`
+    ( derive Half($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$t> {
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                Self::wrap(<$t>::from_ne_bytes(value.$to()))
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                <$t>::$from(self.0.to_ne_bytes())
+            }
+        }
+    )* };
+
+    // Implement the `const` helpers built on `new`/`value`
+    ( derive Const($t:ident) for $Wrapper:ident ) => {
+        impl $Wrapper<$t> {
+            /// Compares with another value, usable in `const` context
+            #[inline(always)]
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.value() == other.value()
+            }
+            /// Compares with a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn const_eq_value(self, value: $t) -> bool {
+                self.value() == value
+            }
+            /// Converts an array of native values, usable in `const` context
+            pub const fn from_native_array<const N: usize>(values: [$t; N]) -> [Self; N] {
+                let mut array = [Self::wrap(<$t>::from_ne_bytes([0; core::mem::size_of::<$t>()])); N];
+                let mut i = 0;
+                while i < N {
+                    array[i] = Self::new(values[i]);
+                    i += 1;
+                }
+                array
+            }
+            /// Converts an array of values to native values, usable in `const` context
+            pub const fn to_native_array<const N: usize>(values: [Self; N]) -> [$t; N] {
+                let mut array = [<$t>::from_ne_bytes([0; core::mem::size_of::<$t>()]); N];
+                let mut i = 0;
+                while i < N {
+                    array[i] = values[i].value();
+                    i += 1;
+                }
+                array
             }
         }
     };
 
-    // Implement a math trait for a wrapper type
-    ( derive Math $Trait:ident :: $fn:ident for $Wrapper:ident ) => {
-        // wrapper = wrapper + native
-        impl<T: Copy + From<$Wrapper<T>> + $Trait> $Trait<T> for $Wrapper<T> where <T as $Trait>::Output: Into<$Wrapper<T>> {
-            type Output = Self;
-            fn $fn(self, other: T) -> Self::Output {
-                $Trait::$fn(T::from(self), other).into()
+    // Implement bi-directional `From` for primitive types, generic over the byte order
+    ( derive Native($( $t:ident ),*) for $Wrapper:ident{$E:ident} ) => { $(
+        impl<$E: Endianness> From<$Wrapper<$t, $E>> for $t {
+            #[inline(always)]
+            fn from(other: $Wrapper<$t, $E>) -> $t {
+                EndianInt::value(other)
             }
         }
-        // wrapper = wrapper + wrapper
-        impl<T: Copy + From<$Wrapper<T>> + $Trait> $Trait for $Wrapper<T> where <T as $Trait>::Output: Into<$Wrapper<T>> {
-            type Output = Self;
-            fn $fn(self, other: Self) -> Self::Output {
-                $Trait::$fn(T::from(self), T::from(other)).into()
+        impl<$E: Endianness> From<$t> for $Wrapper<$t, $E> {
+            #[inline(always)]
+            fn from(other: $t) -> Self {
+                EndianInt::new(other)
+            }
+        }
+    )* };
+
+    // Implement bi-directional `From` for a non-zero integer type, keeping the niche in the stored value
+    ( derive NonZero($( $nz:ident as $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$nz> {
+            /// Creates a value from a native non-zero integer, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $nz) -> Self {
+                match $nz::new(<$t>::from_ne_bytes(value.get().$to())) {
+                    Some(value) => Self::wrap(value),
+                    None => unreachable!(),
+                }
+            }
+            /// Creates a value from a native integer, returning `None` if it's zero
+            #[inline(always)]
+            pub const fn checked_new(value: $t) -> Option<Self> {
+                match $nz::new(value) {
+                    Some(value) => Some(Self::new(value)),
+                    None => None,
+                }
+            }
+            /// Returns the native non-zero integer, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $nz {
+                match $nz::new(<$t>::$from(self.0.get().to_ne_bytes())) {
+                    Some(value) => value,
+                    None => unreachable!(),
+                }
+            }
+            /// Creates a value from its in-memory (storage order) byte representation, returning `None` if it's zero
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Option<Self> {
+                match $nz::new(<$t>::from_ne_bytes(bytes)) {
+                    Some(value) => Some(Self::wrap(value)),
+                    None => None,
+                }
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                self.0.get().to_ne_bytes()
+            }
+        }
+        impl From<$Wrapper<$nz>> for $nz {
+            #[inline(always)]
+            fn from(other: $Wrapper<$nz>) -> $nz {
+                other.value()
+            }
+        }
+        impl From<$Wrapper<$nz>> for $t {
+            #[inline(always)]
+            fn from(other: $Wrapper<$nz>) -> $t {
+                other.value().get()
+            }
+        }
+        impl From<$nz> for $Wrapper<$nz> {
+            #[inline(always)]
+            fn from(other: $nz) -> Self {
+                Self::new(other)
+            }
+        }
+        impl TryFrom<$t> for $Wrapper<$nz> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: $t) -> Result<Self, Error> {
+                Self::checked_new(other).ok_or(Error::new(ErrorKind::OutOfRange))
+            }
+        }
+    )* };
+
+    // Implement a boolean stored as an integer type
+    ( derive Bool($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<Bool<$t>> {
+            /// Creates a value from a boolean, stored as `0` or `1`
+            #[inline(always)]
+            pub const fn new(value: bool) -> Self {
+                Self::wrap(Bool(<$t>::from_ne_bytes((value as $t).$to())))
+            }
+            /// Returns the stored integer
+            pub const fn int_value(self) -> $t {
+                <$t>::$from((self.0).0.to_ne_bytes())
+            }
+            /// Decodes the boolean leniently, treating any non-zero value as `true`
+            #[inline(always)]
+            pub const fn value(self) -> bool {
+                self.int_value() != 0
+            }
+            /// Decodes the boolean strictly, returning `None` if the stored value isn't `0` or `1`
+            #[inline(always)]
+            pub const fn checked_value(self) -> Option<bool> {
+                match self.int_value() {
+                    0 => Some(false),
+                    1 => Some(true),
+                    _ => None,
+                }
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(Bool(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl From<bool> for $Wrapper<Bool<$t>> {
+            #[inline(always)]
+            fn from(other: bool) -> Self {
+                Self::new(other)
+            }
+        }
+        impl From<$Wrapper<Bool<$t>>> for bool {
+            #[inline(always)]
+            fn from(other: $Wrapper<Bool<$t>>) -> bool {
+                other.value()
+            }
+        }
+        impl PartialEq<bool> for $Wrapper<Bool<$t>> {
+            fn eq(&self, other: &bool) -> bool {
+                self.value() == *other
+            }
+        }
+        // Compared and hashed by decoded value, so non-canonical encodings equal `new(true)`
+        impl PartialEq for $Wrapper<Bool<$t>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value() == other.value()
+            }
+        }
+        impl Eq for $Wrapper<Bool<$t>> {}
+        impl core::hash::Hash for $Wrapper<Bool<$t>> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.value().hash(state)
+            }
+        }
+        impl Debug for $Wrapper<Bool<$t>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Debug::fmt(&self.value(), f)
+            }
+        }
+        impl Display for $Wrapper<Bool<$t>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Display::fmt(&self.value(), f)
+            }
+        }
+    )* };
+
+    // Implement a UTF-32 code point stored as a `u32`
+    ( derive Char via $to:ident / $from:ident for $Wrapper:ident ) => {
+        impl $Wrapper<Char32> {
+            /// Creates a value from a character, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: char) -> Self {
+                Self::wrap(Char32(u32::from_ne_bytes((value as u32).$to())))
+            }
+            /// Returns the stored code point, which may not be a valid character
+            pub const fn int_value(self) -> u32 {
+                u32::$from((self.0).0.to_ne_bytes())
+            }
+            /// Returns the character, or `None` if the stored code point isn't a valid character
+            #[inline(always)]
+            pub const fn checked_value(self) -> Option<char> {
+                char::from_u32(self.int_value())
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+                Self::wrap(Char32(u32::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; 4] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl From<char> for $Wrapper<Char32> {
+            #[inline(always)]
+            fn from(other: char) -> Self {
+                Self::new(other)
+            }
+        }
+        impl TryFrom<$Wrapper<Char32>> for char {
+            type Error = core::char::CharTryFromError;
+            #[inline(always)]
+            fn try_from(other: $Wrapper<Char32>) -> Result<char, Self::Error> {
+                char::try_from(other.int_value())
+            }
+        }
+        impl PartialEq<char> for $Wrapper<Char32> {
+            fn eq(&self, other: &char) -> bool {
+                self.int_value() == *other as u32
+            }
+        }
+        impl PartialEq for $Wrapper<Char32> {
+            fn eq(&self, other: &Self) -> bool {
+                self.int_value() == other.int_value()
+            }
+        }
+        impl Eq for $Wrapper<Char32> {}
+        impl Debug for $Wrapper<Char32> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                match self.checked_value() {
+                    Some(c) => Debug::fmt(&c, f),
+                    None => write!(f, "{:#x}", self.int_value()),
+                }
             }
         }
     };
-}
 
-#[repr(transparent)]
-#[derive(Copy, Clone)]
-/// Wrapper type for data that's explicitly stored in memory as big endian
-pub struct BigEndian<T>(T);
+    // Implement a packed binary-coded decimal number stored as an integer type
+    ( derive Bcd($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<Bcd<$t>> {
+            /// Creates a value by encoding a native integer, returning `None` if it has too many digits
+            #[inline(always)]
+            pub const fn checked_new(value: $t) -> Option<Self> {
+                let mut value = value;
+                let mut bcd: $t = 0;
+                let mut shift = 0;
+                while value != 0 {
+                    if shift >= <$t>::BITS {
+                        return None;
+                    }
+                    bcd |= (value % 10) << shift;
+                    value /= 10;
+                    shift += 4;
+                }
+                Some(Self::wrap(Bcd(<$t>::from_ne_bytes(bcd.$to()))))
+            }
+            /// Creates a value by encoding a native integer, panicking if it has too many digits
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                match Self::checked_new(value) {
+                    Some(value) => value,
+                    None => panic!("value out of range for stored type"),
+                }
+            }
+            /// Returns the stored BCD-encoded integer
+            pub const fn int_value(self) -> $t {
+                <$t>::$from((self.0).0.to_ne_bytes())
+            }
+            /// Decodes the value, returning `None` if any digit nibble is greater than 9
+            #[inline(always)]
+            pub const fn checked_value(self) -> Option<$t> {
+                let bcd = self.int_value();
+                let mut value: $t = 0;
+                let mut shift = <$t>::BITS;
+                while shift > 0 {
+                    shift -= 4;
+                    let digit = (bcd >> shift) & 0xf;
+                    if digit > 9 {
+                        return None;
+                    }
+                    value = value * 10 + digit;
+                }
+                Some(value)
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(Bcd(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl TryFrom<$t> for $Wrapper<Bcd<$t>> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: $t) -> Result<Self, Error> {
+                // Every nibble holds a decimal digit
+                let max = <$t>::pow(10, <$t>::BITS / 4) - 1;
+                Self::checked_new(other).ok_or_else(|| Error::out_of_range(max, other))
+            }
+        }
+        impl PartialEq<$t> for $Wrapper<Bcd<$t>> {
+            fn eq(&self, other: &$t) -> bool {
+                self.checked_value() == Some(*other)
+            }
+        }
+        impl PartialEq for $Wrapper<Bcd<$t>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.int_value() == other.int_value()
+            }
+        }
+        impl Eq for $Wrapper<Bcd<$t>> {}
+        impl Debug for $Wrapper<Bcd<$t>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                match self.checked_value() {
+                    Some(value) => Debug::fmt(&value, f),
+                    None => write!(f, "{:#x}", self.int_value()),
+                }
+            }
+        }
+    )* };
 
-#[repr(transparent)]
-#[derive(Copy, Clone)]
-/// Wrapper type for data that's explicitly stored in memory as little endian
-pub struct LittleEndian<T>(T);
+    // Implement bi-directional `From` for an array, with each element stored in the wrapper's byte order
+    ( derive Array($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl<const N: usize> $Wrapper<[$t; N]> {
+            /// Creates a value from a native array, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: [$t; N]) -> Self {
+                let mut array = value;
+                let mut i = 0;
+                while i < N {
+                    array[i] = <$t>::from_ne_bytes(value[i].$to());
+                    i += 1;
+                }
+                Self::wrap(array)
+            }
+            /// Returns the native array, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> [$t; N] {
+                let mut array = self.0;
+                let mut i = 0;
+                while i < N {
+                    array[i] = <$t>::$from(self.0[i].to_ne_bytes());
+                    i += 1;
+                }
+                array
+            }
+            /// Returns a single element, or `None` if the index is out of bounds
+            pub const fn get_at(&self, index: usize) -> Option<$t> {
+                if index < N {
+                    Some(<$t>::$from(self.0[index].to_ne_bytes()))
+                } else {
+                    None
+                }
+            }
+            /// Replaces a single element, panicking if the index is out of bounds
+            pub fn set_at(&mut self, index: usize, value: $t) {
+                self.0[index] = <$t>::from_ne_bytes(value.$to());
+            }
+        }
+        impl<const N: usize> From<$Wrapper<[$t; N]>> for [$t; N] {
+            #[inline(always)]
+            fn from(other: $Wrapper<[$t; N]>) -> Self {
+                other.value()
+            }
+        }
+        impl<const N: usize> From<[$t; N]> for $Wrapper<[$t; N]> {
+            #[inline(always)]
+            fn from(other: [$t; N]) -> Self {
+                Self::new(other)
+            }
+        }
+    )* };
+
+    // Implement a legacy signed integer encoding, converting to and from two's complement
+    ( derive Signed $Kind:ident($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$Kind<$t>> {
+            /// Creates a value by encoding a native integer, returning `None` for `MIN` which has no encoding
+            #[inline(always)]
+            pub const fn checked_new(value: $t) -> Option<Self> {
+                if value == <$t>::MIN {
+                    return None;
+                }
+                let bits = if value < 0 { $Kind::<$t>::encode_negative(value) } else { value };
+                Some(Self::wrap($Kind(<$t>::from_ne_bytes(bits.$to()))))
+            }
+            /// Creates a value by encoding a native integer, panicking for `MIN` which has no encoding
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                match Self::checked_new(value) {
+                    Some(value) => value,
+                    None => panic!("value out of range for stored type"),
+                }
+            }
+            /// Returns the stored bits, reinterpreted as a two's complement integer
+            pub const fn int_value(self) -> $t {
+                <$t>::$from((self.0).0.to_ne_bytes())
+            }
+            /// Decodes the value, with negative zero becoming zero
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                match self.int_value() {
+                    bits if bits < 0 => $Kind::<$t>::decode_negative(bits),
+                    bits => bits,
+                }
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap($Kind(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl From<$Wrapper<$Kind<$t>>> for $t {
+            #[inline(always)]
+            fn from(other: $Wrapper<$Kind<$t>>) -> Self {
+                other.value()
+            }
+        }
+        impl TryFrom<$t> for $Wrapper<$Kind<$t>> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: $t) -> Result<Self, Error> {
+                Self::checked_new(other).ok_or(Error::new(ErrorKind::OutOfRange))
+            }
+        }
+        impl PartialEq<$t> for $Wrapper<$Kind<$t>> {
+            fn eq(&self, other: &$t) -> bool {
+                self.value() == *other
+            }
+        }
+        impl PartialEq for $Wrapper<$Kind<$t>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value() == other.value()
+            }
+        }
+        impl Eq for $Wrapper<$Kind<$t>> {}
+        impl Debug for $Wrapper<$Kind<$t>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Debug::fmt(&self.value(), f)
+            }
+        }
+    )* };
 
-wrapper!(BigEndian::to_be, LittleEndian::to_le);
+    // Implement an optional integer where a sentinel value `S` (truncated to the stored type) represents `None`
+    ( derive Sentinel($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl<const S: u128> $Wrapper<Sentinel<$t, S>> {
+            /// The stored value representing `None`
+            pub const NONE: Self = Self::wrap(Sentinel(<$t>::from_ne_bytes((S as $t).$to())));
 
-// Big-endian type aliases
-pub type BEu128 = BigEndian<u128>;
-pub type BEu64 = BigEndian<u64>;
-pub type BEu32 = BigEndian<u32>;
-pub type BEu16 = BigEndian<u16>;
-pub type BEu8 = BigEndian<u8>;
-pub type BEi128 = BigEndian<i128>;
-pub type BEi64 = BigEndian<i64>;
-pub type BEi32 = BigEndian<i32>;
-pub type BEi16 = BigEndian<i16>;
-pub type BEi8 = BigEndian<i8>;
+            /// Creates a value, returning `None` if `Some` value would be stored as the sentinel
+            #[inline(always)]
+            pub const fn checked_new(value: Option<$t>) -> Option<Self> {
+                match value {
+                    Some(value) if value == S as $t => None,
+                    Some(value) => Some(Self::wrap(Sentinel(<$t>::from_ne_bytes(value.$to())))),
+                    None => Some(Self::NONE),
+                }
+            }
+            /// Creates a value, panicking if `Some` value would be stored as the sentinel
+            #[inline(always)]
+            pub const fn new(value: Option<$t>) -> Self {
+                match Self::checked_new(value) {
+                    Some(value) => value,
+                    None => panic!("value out of range for stored type"),
+                }
+            }
+            /// Returns the native value, or `None` if the sentinel is stored
+            #[inline(always)]
+            pub const fn value(self) -> Option<$t> {
+                match <$t>::$from((self.0).0.to_ne_bytes()) {
+                    value if value == S as $t => None,
+                    value => Some(value),
+                }
+            }
+            /// Returns `true` if the sentinel is stored
+            pub const fn is_none(self) -> bool {
+                self.value().is_none()
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(Sentinel(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl<const S: u128> From<$Wrapper<Sentinel<$t, S>>> for Option<$t> {
+            #[inline(always)]
+            fn from(other: $Wrapper<Sentinel<$t, S>>) -> Self {
+                other.value()
+            }
+        }
+        impl<const S: u128> TryFrom<Option<$t>> for $Wrapper<Sentinel<$t, S>> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: Option<$t>) -> Result<Self, Error> {
+                Self::checked_new(other).ok_or(Error::new(ErrorKind::OutOfRange))
+            }
+        }
+        impl<const S: u128> PartialEq<Option<$t>> for $Wrapper<Sentinel<$t, S>> {
+            fn eq(&self, other: &Option<$t>) -> bool {
+                self.value() == *other
+            }
+        }
+        impl<const S: u128> PartialEq for $Wrapper<Sentinel<$t, S>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value() == other.value()
+            }
+        }
+        impl<const S: u128> Eq for $Wrapper<Sentinel<$t, S>> {}
+        impl<const S: u128> Debug for $Wrapper<Sentinel<$t, S>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Debug::fmt(&self.value(), f)
+            }
+        }
+    )* };
 
-// Little-endian type aliases
-pub type LEu128 = LittleEndian<u128>;
-pub type LEu64 = LittleEndian<u64>;
-pub type LEu32 = LittleEndian<u32>;
-pub type LEu16 = LittleEndian<u16>;
-pub type LEu8 = LittleEndian<u8>;
-pub type LEi128 = LittleEndian<i128>;
-pub type LEi64 = LittleEndian<i64>;
-pub type LEi32 = LittleEndian<i32>;
-pub type LEi16 = LittleEndian<i16>;
-pub type LEi8 = LittleEndian<i8>;
+    // Implement bi-directional `From` for an integer type with explicit overflow behaviour (e.g. `Wrapping<u32>`),
+    // so that the generic operator implementations follow that behaviour
+    ( derive Overflow $Kind:ident($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<$Kind<$t>> {
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $Kind<$t>) -> Self {
+                Self::wrap($Kind(<$t>::from_ne_bytes(value.0.$to())))
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $Kind<$t> {
+                $Kind(<$t>::$from((self.0).0.to_ne_bytes()))
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap($Kind(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl From<$Wrapper<$Kind<$t>>> for $Kind<$t> {
+            #[inline(always)]
+            fn from(other: $Wrapper<$Kind<$t>>) -> Self {
+                other.value()
+            }
+        }
+        impl From<$Kind<$t>> for $Wrapper<$Kind<$t>> {
+            #[inline(always)]
+            fn from(other: $Kind<$t>) -> Self {
+                Self::new(other)
+            }
+        }
+    )* };
 
-#[cfg(test)]
+    // Implement conversions between IP addresses and their integer representation
+    ( derive Net($( $addr:ident as $t:ident ),*) for $Wrapper:ident $g:tt ) => {
+        $( wrapper!(derive Net $addr as $t for $Wrapper $g); )*
+    };
+    ( derive Net $addr:ident as $t:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness)?> From<$addr> for $Wrapper<$t $(, $E)?> {
+            #[inline(always)]
+            fn from(other: $addr) -> Self {
+                <$t>::from(other).into()
+            }
+        }
+        impl<$($E: Endianness)?> From<$Wrapper<$t $(, $E)?>> for $addr {
+            #[inline(always)]
+            fn from(other: $Wrapper<$t $(, $E)?>) -> Self {
+                <$addr>::from(<$t>::from(other))
+            }
+        }
+    };
+
+    // Implement a Unix timestamp (whole seconds since 1970-01-01 00:00:00 UTC) stored as an integer type
+    ( derive UnixTime($( $t:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl $Wrapper<UnixTime<$t>> {
+            /// Creates a timestamp from a number of seconds since the Unix epoch
+            pub const fn from_secs(secs: $t) -> Self {
+                Self::wrap(UnixTime(<$t>::from_ne_bytes(secs.$to())))
+            }
+            /// Returns the number of seconds since the Unix epoch
+            pub const fn secs(self) -> $t {
+                <$t>::$from((self.0).0.to_ne_bytes())
+            }
+            /// Creates a timestamp from the time since the Unix epoch, discarding any fractional second,
+            /// returning `None` if it does not fit in the stored type
+            pub fn from_duration(duration: Duration) -> Option<Self> {
+                <$t>::try_from(duration.as_secs()).ok().map(Self::from_secs)
+            }
+            /// Returns the time since the Unix epoch, or `None` if the timestamp is before the epoch
+            pub fn to_duration(self) -> Option<Duration> {
+                u64::try_from(self.secs()).ok().map(Duration::from_secs)
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(UnixTime(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl TryFrom<Duration> for $Wrapper<UnixTime<$t>> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: Duration) -> Result<Self, Error> {
+                Self::from_duration(other).ok_or_else(|| Error::out_of_range(<$t>::MAX as u64, other.as_secs()))
+            }
+        }
+        impl TryFrom<$Wrapper<UnixTime<$t>>> for Duration {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: $Wrapper<UnixTime<$t>>) -> Result<Self, Error> {
+                other.to_duration().ok_or(Error::new(ErrorKind::OutOfRange))
+            }
+        }
+        impl PartialEq for $Wrapper<UnixTime<$t>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.secs() == other.secs()
+            }
+        }
+        impl Eq for $Wrapper<UnixTime<$t>> {}
+        impl PartialOrd for $Wrapper<UnixTime<$t>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for $Wrapper<UnixTime<$t>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.secs().cmp(&other.secs())
+            }
+        }
+        impl Debug for $Wrapper<UnixTime<$t>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Debug::fmt(&self.secs(), f)
+            }
+        }
+    )* };
+
+    // Implement a fixed-point number with `F` fractional bits stored as an integer type,
+    // using a wider type for intermediate results of multiplication and division
+    ( derive Fixed($( $t:ident as $wide:ident ),*) via $to:ident / $from:ident for $Wrapper:ident ) => { $(
+        impl<const F: u32> $Wrapper<Fixed<$t, F>> {
+            const SCALE: f64 = (1u128 << F) as f64;
+
+            /// Creates a value from the integer representation (the value multiplied by `2^F`)
+            pub const fn from_bits(bits: $t) -> Self {
+                Self::wrap(Fixed(<$t>::from_ne_bytes(bits.$to())))
+            }
+            /// Returns the integer representation (the value multiplied by `2^F`)
+            pub const fn to_bits(self) -> $t {
+                <$t>::$from((self.0).0.to_ne_bytes())
+            }
+            /// Creates a value from a float, rounding to the nearest representable value and saturating if out of range
+            pub fn from_f64(value: f64) -> Self {
+                let scaled = value * Self::SCALE;
+                Self::from_bits((if scaled < 0.0 { scaled - 0.5 } else { scaled + 0.5 }) as $t)
+            }
+            /// Creates a value from a float, rounding to the nearest representable value and saturating if out of range
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(f64::from(value))
+            }
+            /// Returns the value as a float
+            pub fn to_f64(self) -> f64 {
+                self.to_bits() as f64 / Self::SCALE
+            }
+            /// Returns the value as a float
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(Fixed(<$t>::from_ne_bytes(bytes)))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (self.0).0.to_ne_bytes()
+            }
+        }
+        impl<const F: u32> Add for $Wrapper<Fixed<$t, F>> {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() + other.to_bits())
+            }
+        }
+        impl<const F: u32> Sub for $Wrapper<Fixed<$t, F>> {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() - other.to_bits())
+            }
+        }
+        impl<const F: u32> Mul for $Wrapper<Fixed<$t, F>> {
+            type Output = Self;
+            fn mul(self, other: Self) -> Self {
+                Self::from_bits(((self.to_bits() as $wide * other.to_bits() as $wide) >> F) as $t)
+            }
+        }
+        impl<const F: u32> Div for $Wrapper<Fixed<$t, F>> {
+            type Output = Self;
+            fn div(self, other: Self) -> Self {
+                Self::from_bits((((self.to_bits() as $wide) << F) / other.to_bits() as $wide) as $t)
+            }
+        }
+        impl<const F: u32> PartialEq for $Wrapper<Fixed<$t, F>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+        }
+        impl<const F: u32> Eq for $Wrapper<Fixed<$t, F>> {}
+        impl<const F: u32> PartialOrd for $Wrapper<Fixed<$t, F>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl<const F: u32> Ord for $Wrapper<Fixed<$t, F>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.to_bits().cmp(&other.to_bits())
+            }
+        }
+        impl<const F: u32> Debug for $Wrapper<Fixed<$t, F>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Debug::fmt(&self.to_f64(), f)
+            }
+        }
+        impl<const F: u32> Display for $Wrapper<Fixed<$t, F>> {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                Display::fmt(&self.to_f64(), f)
+            }
+        }
+    )* };
+
+    // Implement an `EndianRepr` type stored as its integer representation
+    ( derive Enum for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness,)? R: EndianRepr> $Wrapper<Enum<R> $(, $E)?>
+        where
+            R::Repr: From<$Wrapper<R::Repr $(, $E)?>> + Into<$Wrapper<R::Repr $(, $E)?>>,
+        {
+            /// Creates a value by encoding its integer representation
+            #[inline(always)]
+            pub fn new(value: R) -> Self {
+                let repr: $Wrapper<R::Repr $(, $E)?> = value.to_repr().into();
+                Self::wrap(Enum(repr.0))
+            }
+            /// Returns the stored integer representation, which may not be a valid value
+            pub fn int_value(self) -> R::Repr {
+                R::Repr::from(<$Wrapper<R::Repr $(, $E)?>>::wrap((self.0).0))
+            }
+            /// Returns the decoded value, or `None` if the stored integer isn't a valid representation
+            #[inline(always)]
+            pub fn checked_value(self) -> Option<R> {
+                R::from_repr(self.int_value())
+            }
+        }
+        impl<$($E: Endianness,)? R: EndianRepr> From<R> for $Wrapper<Enum<R> $(, $E)?>
+        where
+            R::Repr: From<$Wrapper<R::Repr $(, $E)?>> + Into<$Wrapper<R::Repr $(, $E)?>>,
+        {
+            #[inline(always)]
+            fn from(other: R) -> Self {
+                Self::new(other)
+            }
+        }
+        impl<$($E: Endianness,)? R: EndianRepr + PartialEq> PartialEq<R> for $Wrapper<Enum<R> $(, $E)?>
+        where
+            R::Repr: From<$Wrapper<R::Repr $(, $E)?>> + Into<$Wrapper<R::Repr $(, $E)?>>,
+        {
+            fn eq(&self, other: &R) -> bool {
+                self.checked_value().as_ref() == Some(other)
+            }
+        }
+        impl<$($E: Endianness,)? R: EndianRepr> PartialEq for $Wrapper<Enum<R> $(, $E)?>
+        where
+            R::Repr: From<$Wrapper<R::Repr $(, $E)?>> + Into<$Wrapper<R::Repr $(, $E)?>> + PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.int_value() == other.int_value()
+            }
+        }
+        impl<$($E: Endianness,)? R: EndianRepr + Debug> Debug for $Wrapper<Enum<R> $(, $E)?>
+        where
+            R::Repr: From<$Wrapper<R::Repr $(, $E)?>> + Into<$Wrapper<R::Repr $(, $E)?>> + Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                match self.checked_value() {
+                    Some(value) => Debug::fmt(&value, f),
+                    None => Debug::fmt(&self.int_value(), f),
+                }
+            }
+        }
+    };
+
+    // Implement runtime byte order conversion for primitive number types
+    ( derive Dynamic($( $t:ident ),*) ) => { $(
+        impl DynamicEndian<$t> {
+            /// Creates a value from a native value, stored in the given byte order
+            #[inline(always)]
+            pub const fn new(value: $t, order: ByteOrder) -> Self {
+                Self(<$t>::from_ne_bytes(match order {
+                    ByteOrder::Big => value.to_be_bytes(),
+                    ByteOrder::Little => value.to_le_bytes(),
+                }))
+            }
+            /// Returns the native value, interpreting the stored value in the given byte order
+            #[inline(always)]
+            pub const fn get(self, order: ByteOrder) -> $t {
+                match order {
+                    ByteOrder::Big => <$t>::from_be_bytes(self.0.to_ne_bytes()),
+                    ByteOrder::Little => <$t>::from_le_bytes(self.0.to_ne_bytes()),
+                }
+            }
+            /// Stores a native value in the given byte order
+            #[inline(always)]
+            pub fn set(&mut self, value: $t, order: ByteOrder) {
+                *self = Self::new(value, order);
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self(<$t>::from_ne_bytes(bytes))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                self.0.to_ne_bytes()
+            }
+        }
+    )* };
+
+    // Implement bi-directional `From` for types stored with their big endian bytes in a permuted order
+    ( derive Permuted($( $t:ident ),*) as $perm:expr, for $Wrapper:ident ) => { $(
+        impl $Wrapper<$t> {
+            // `storage[i] = big_endian[PERM[i]]`
+            const PERM: [usize; core::mem::size_of::<$t>()] = $perm;
+
+            /// Creates a value from a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn new(value: $t) -> Self {
+                let be = value.to_be_bytes();
+                let mut bytes = [0u8; core::mem::size_of::<$t>()];
+                let mut i = 0;
+                while i < bytes.len() {
+                    bytes[i] = be[Self::PERM[i]];
+                    i += 1;
+                }
+                Self::wrap(<$t>::from_ne_bytes(bytes))
+            }
+            /// Returns the native value, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $t {
+                let bytes = self.0.to_ne_bytes();
+                let mut be = [0u8; core::mem::size_of::<$t>()];
+                let mut i = 0;
+                while i < bytes.len() {
+                    be[Self::PERM[i]] = bytes[i];
+                    i += 1;
+                }
+                <$t>::from_be_bytes(be)
+            }
+            /// Compares with another value, usable in `const` context
+            #[inline(always)]
+            pub const fn const_eq(self, other: Self) -> bool {
+                self.value() == other.value()
+            }
+            /// Compares with a native value, usable in `const` context
+            #[inline(always)]
+            pub const fn const_eq_value(self, value: $t) -> bool {
+                self.value() == value
+            }
+        }
+        impl From<$Wrapper<$t>> for $t {
+            #[inline(always)]
+            fn from(other: $Wrapper<$t>) -> $t {
+                other.value()
+            }
+        }
+        impl From<$t> for $Wrapper<$t> {
+            #[inline(always)]
+            fn from(other: $t) -> Self {
+                Self::new(other)
+            }
+        }
+    )* };
+
+    // Implement byte representation access for a primitive number type
+    ( derive Bytes $t:ident for $Wrapper:ident ) => {
+        impl $Wrapper<$t> {
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                Self::wrap(<$t>::from_ne_bytes(bytes))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                self.0.to_ne_bytes()
+            }
+            /// Returns a reference to the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn as_bytes(&self) -> &[u8; core::mem::size_of::<$t>()] {
+                // Safety: primitive numbers have no padding and the byte array has an alignment of 1
+                unsafe { &*(self as *const Self as *const [u8; core::mem::size_of::<$t>()]) }
+            }
+            /// Returns a mutable reference to the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn as_bytes_mut(&mut self) -> &mut [u8; core::mem::size_of::<$t>()] {
+                // Safety: primitive numbers have no padding, every bit pattern is valid, and the byte array has an alignment of 1
+                unsafe { &mut *(self as *mut Self as *mut [u8; core::mem::size_of::<$t>()]) }
+            }
+        }
+    };
+
+    // Implement slice based byte access for a primitive number type,
+    // for wrappers without an `Endianness` (which get generic implementations instead)
+    ( derive ByteSlice $t:ident for $Wrapper:ident ) => {
+        impl $Wrapper<$t> {
+            /// Returns an iterator over the in-memory (storage order) bytes of the value
+            #[inline(always)]
+            pub fn bytes(self) -> core::array::IntoIter<u8, { core::mem::size_of::<$t>() }> {
+                IntoIterator::into_iter(self.to_bytes())
+            }
+            /// Reads a value from its storage order bytes at `offset` within `buf`, returning `None` if out of bounds
+            #[inline(always)]
+            pub fn read_from(buf: &[u8], offset: usize) -> Option<Self> {
+                let bytes = buf.get(offset..)?.get(..core::mem::size_of::<$t>())?;
+                Some(Self::from_bytes(<[u8; core::mem::size_of::<$t>()]>::try_from(bytes).ok()?))
+            }
+            /// Writes the storage order bytes of the value at `offset` within `buf`, returning `None` if out of bounds
+            #[inline(always)]
+            pub fn write_into(&self, buf: &mut [u8], offset: usize) -> Option<()> {
+                let bytes = buf.get_mut(offset..)?.get_mut(..core::mem::size_of::<$t>())?;
+                bytes.copy_from_slice(self.as_bytes());
+                Some(())
+            }
+        }
+        // `Borrow<[u8]>` is intentionally not implemented, as byte-wise ordering doesn't match value ordering
+        impl AsRef<[u8]> for $Wrapper<$t> {
+            #[inline(always)]
+            fn as_ref(&self) -> &[u8] {
+                self.as_bytes()
+            }
+        }
+        impl EndianValue for $Wrapper<$t> {
+            type Bytes = [u8; core::mem::size_of::<$t>()];
+            #[inline(always)]
+            fn from_storage(bytes: Self::Bytes) -> Self {
+                Self::from_bytes(bytes)
+            }
+            #[inline(always)]
+            fn to_storage(self) -> Self::Bytes {
+                self.to_bytes()
+            }
+        }
+        impl TryFrom<&[u8]> for $Wrapper<$t> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+                match <[u8; core::mem::size_of::<$t>()]>::try_from(bytes) {
+                    Ok(bytes) => Ok(Self::from_bytes(bytes)),
+                    Err(_) => Err(Error::length(core::mem::size_of::<$t>(), bytes.len())),
+                }
+            }
+        }
+        impl IntoIterator for $Wrapper<$t> {
+            type Item = u8;
+            type IntoIter = core::array::IntoIter<u8, { core::mem::size_of::<$t>() }>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.bytes()
+            }
+        }
+    };
+
+    // Implement inherent methods common to all integer types
+    ( derive Int $t:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness)?> $Wrapper<$t $(, $E)?> {
+            /// Returns big endian bytes with the sign bit flipped, so keys sort with `memcmp` in value order
+            pub fn to_sortable_bytes(self) -> [u8; core::mem::size_of::<$t>()] {
+                (<$t>::from(self) ^ <$t>::MIN).to_be_bytes()
+            }
+            /// Creates a value from bytes produced by `to_sortable_bytes`
+            pub fn from_sortable_bytes(bytes: [u8; core::mem::size_of::<$t>()]) -> Self {
+                (<$t>::from_be_bytes(bytes) ^ <$t>::MIN).into()
+            }
+            /// Compares with a native integer that the value losslessly widens into, such as `u64` for a `u32`
+            #[inline(always)]
+            pub fn eq_widened<W: From<$t> + PartialEq>(self, other: W) -> bool {
+                W::from(<$t>::from(self)) == other
+            }
+            /// Calculates the middle point of `self` and `rhs`, rounded towards zero, without overflowing
+            pub fn midpoint(self, rhs: impl Into<$t>) -> Self {
+                let (a, b) = (<$t>::from(self), rhs.into());
+                // The shared bits plus half the differing ones is the midpoint rounded down
+                let mid = (a & b) + ((a ^ b) >> 1);
+                let odd = (a ^ b) & 1 != 0;
+                (mid + (mid < <$t>::default() && odd) as $t).into()
+            }
+            /// Returns the square root of the value, rounded down, panicking if it's negative
+            pub fn isqrt(self) -> Self {
+                let value = <$t>::from(self);
+                assert!(value >= <$t>::default(), "argument of integer square root cannot be negative");
+                // Finds a bit of the root at a time, from the highest power of four in range
+                let (mut rem, mut root) = (value, 0 as $t);
+                let mut bit = (1 as $t) << ((<$t>::BITS - 2) & !1);
+                while bit > value {
+                    bit >>= 2;
+                }
+                while bit != 0 {
+                    if rem >= root + bit {
+                        rem -= root + bit;
+                        root = (root >> 1) + bit;
+                    } else {
+                        root >>= 1;
+                    }
+                    bit >>= 2;
+                }
+                root.into()
+            }
+            /// Casts the value to `u8` with the semantics of a primitive `as` cast
+            pub fn as_u8(self) -> u8 {
+                <$t>::from(self) as u8
+            }
+            /// Casts the value to `u16` with the semantics of a primitive `as` cast
+            pub fn as_u16(self) -> u16 {
+                <$t>::from(self) as u16
+            }
+            /// Casts the value to `u32` with the semantics of a primitive `as` cast
+            pub fn as_u32(self) -> u32 {
+                <$t>::from(self) as u32
+            }
+            /// Casts the value to `u64` with the semantics of a primitive `as` cast
+            pub fn as_u64(self) -> u64 {
+                <$t>::from(self) as u64
+            }
+            /// Casts the value to `u128` with the semantics of a primitive `as` cast
+            pub fn as_u128(self) -> u128 {
+                <$t>::from(self) as u128
+            }
+            /// Casts the value to `usize` with the semantics of a primitive `as` cast
+            pub fn as_usize(self) -> usize {
+                <$t>::from(self) as usize
+            }
+            /// Casts the value to `i8` with the semantics of a primitive `as` cast
+            pub fn as_i8(self) -> i8 {
+                <$t>::from(self) as i8
+            }
+            /// Casts the value to `i16` with the semantics of a primitive `as` cast
+            pub fn as_i16(self) -> i16 {
+                <$t>::from(self) as i16
+            }
+            /// Casts the value to `i32` with the semantics of a primitive `as` cast
+            pub fn as_i32(self) -> i32 {
+                <$t>::from(self) as i32
+            }
+            /// Casts the value to `i64` with the semantics of a primitive `as` cast
+            pub fn as_i64(self) -> i64 {
+                <$t>::from(self) as i64
+            }
+            /// Casts the value to `i128` with the semantics of a primitive `as` cast
+            pub fn as_i128(self) -> i128 {
+                <$t>::from(self) as i128
+            }
+            /// Casts the value to `isize` with the semantics of a primitive `as` cast
+            pub fn as_isize(self) -> isize {
+                <$t>::from(self) as isize
+            }
+        }
+    };
+
+    // Implement inherent methods specific to unsigned integer types
+    ( derive Unsigned $t:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness)?> $Wrapper<$t $(, $E)?> {
+            /// Calculates `self + rhs + carry`, returning the sum and the output carry
+            pub fn carrying_add(self, rhs: impl Into<$t>, carry: bool) -> (Self, bool) {
+                let (sum, first) = <$t>::from(self).overflowing_add(rhs.into());
+                let (sum, second) = sum.overflowing_add(carry as $t);
+                (sum.into(), first | second)
+            }
+            /// Calculates `self - rhs - borrow`, returning the difference and the output borrow
+            pub fn borrowing_sub(self, rhs: impl Into<$t>, borrow: bool) -> (Self, bool) {
+                let (diff, first) = <$t>::from(self).overflowing_sub(rhs.into());
+                let (diff, second) = diff.overflowing_sub(borrow as $t);
+                (diff.into(), first | second)
+            }
+            /// Calculates the complete product `self * rhs`, returning the low and high halves
+            pub fn widening_mul(self, rhs: impl Into<$t>) -> (Self, Self) {
+                // Long multiplication of half-width digits, as there may be no wider type
+                let half = <$t>::BITS / 2;
+                let mask = <$t>::MAX >> half;
+                let (a, b) = (<$t>::from(self), rhs.into());
+                let (a_low, a_high, b_low, b_high) = (a & mask, a >> half, b & mask, b >> half);
+                let (cross, cross_carry) = (a_low * b_high).overflowing_add(a_high * b_low);
+                let (low, low_carry) = (a_low * b_low).overflowing_add(cross << half);
+                let high = a_high * b_high + (cross >> half) + ((cross_carry as $t) << half) + low_carry as $t;
+                (low.into(), high.into())
+            }
+            /// Calculates the smallest value greater than or equal to `self` that is a multiple of `rhs`
+            pub fn next_multiple_of(self, rhs: impl Into<$t>) -> Self {
+                <$t>::from(self).next_multiple_of(rhs.into()).into()
+            }
+            /// Like `next_multiple_of`, but returns `None` if `rhs` is zero or the result would overflow
+            pub fn checked_next_multiple_of(self, rhs: impl Into<$t>) -> Option<Self> {
+                <$t>::from(self).checked_next_multiple_of(rhs.into()).map(Self::from)
+            }
+            /// Returns the bit at `index` using the given bit numbering, panicking if it's out of range
+            pub fn bit(self, index: u32, order: BitOrder) -> bool {
+                self.bits(index, 1, order) != 0
+            }
+            /// Extracts the `len` bit field starting at `start` using the given bit numbering,
+            /// panicking if it's out of range
+            pub fn bits(self, start: u32, len: u32, order: BitOrder) -> $t {
+                let shift = order.shift(start, len, <$t>::BITS);
+                (<$t>::from(self) >> shift) & (<$t>::MAX >> (<$t>::BITS - len))
+            }
+            /// Replaces the `len` bit field starting at `start` using the given bit numbering,
+            /// panicking if it's out of range and truncating `value` to the field width
+            pub fn set_bits(&mut self, start: u32, len: u32, order: BitOrder, value: $t) {
+                let shift = order.shift(start, len, <$t>::BITS);
+                let mask = (<$t>::MAX >> (<$t>::BITS - len)) << shift;
+                let native = <$t>::from(*self);
+                *self = ((native & !mask) | ((value << shift) & mask)).into();
+            }
+        }
+    };
+
+    // Implement an integer stored as `$n` bytes, converting to and from the smallest native type that fits
+    ( derive ByteInt $T:ident<$( $n:literal as $native:ident ),*> via $to:ident / $from:ident for $Wrapper:ident ) => {
+        $( wrapper!(derive ByteInt $T<$n> as $native via $to/$from for $Wrapper); )*
+    };
+    ( derive ByteInt $T:ident<$n:literal> as $native:ident via $to:ident / $from:ident for $Wrapper:ident ) => {
+        impl $Wrapper<$T<$n>> {
+            // Offset of the stored bytes within the native representation, and the shift used to sign extend
+            const OFFSET: usize = if 1u16.$to()[0] == 0 { core::mem::size_of::<$native>() - $n } else { 0 };
+            const SHIFT: u32 = ((core::mem::size_of::<$native>() - $n) * 8) as u32;
+
+            /// Creates a value from a native integer, panicking if it doesn't fit in the stored width
+            #[inline(always)]
+            pub const fn new(value: $native) -> Self {
+                match Self::checked_new(value) {
+                    Some(value) => value,
+                    None => panic!("value out of range for stored width"),
+                }
+            }
+            /// Creates a value from a native integer, returning `None` if it doesn't fit in the stored width
+            #[inline(always)]
+            pub const fn checked_new(value: $native) -> Option<Self> {
+                if (value << Self::SHIFT) >> Self::SHIFT != value {
+                    return None;
+                }
+                let native = value.$to();
+                let mut bytes = [0u8; $n];
+                let mut i = 0;
+                while i < $n {
+                    bytes[i] = native[Self::OFFSET + i];
+                    i += 1;
+                }
+                Some(Self::from_bytes(bytes))
+            }
+            /// Returns the native integer, usable in `const` context
+            #[inline(always)]
+            pub const fn value(self) -> $native {
+                let mut native = [0u8; core::mem::size_of::<$native>()];
+                let mut i = 0;
+                while i < $n {
+                    native[Self::OFFSET + i] = (self.0).0[i];
+                    i += 1;
+                }
+                (<$native>::$from(native) << Self::SHIFT) >> Self::SHIFT
+            }
+            /// Creates a value from its in-memory (storage order) byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; $n]) -> Self {
+                Self::wrap($T(bytes))
+            }
+            /// Returns the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; $n] {
+                (self.0).0
+            }
+            /// Returns a reference to the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn as_bytes(&self) -> &[u8; $n] {
+                &(self.0).0
+            }
+            /// Returns a mutable reference to the in-memory (storage order) byte representation of the value
+            #[inline(always)]
+            pub const fn as_bytes_mut(&mut self) -> &mut [u8; $n] {
+                &mut (self.0).0
+            }
+        }
+        impl From<$Wrapper<$T<$n>>> for $native {
+            #[inline(always)]
+            fn from(other: $Wrapper<$T<$n>>) -> $native {
+                other.value()
+            }
+        }
+        impl TryFrom<$native> for $Wrapper<$T<$n>> {
+            type Error = Error;
+            #[inline(always)]
+            fn try_from(other: $native) -> Result<Self, Error> {
+                let max = <$native>::MAX >> Self::SHIFT;
+                Self::checked_new(other).ok_or_else(|| Error::out_of_range(max, other))
+            }
+        }
+        impl PartialEq<$native> for $Wrapper<$T<$n>> {
+            fn eq(&self, other: &$native) -> bool {
+                self.value() == *other
+            }
+        }
+        impl PartialEq for $Wrapper<$T<$n>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value() == other.value()
+            }
+        }
+        impl Eq for $Wrapper<$T<$n>> {}
+        impl PartialOrd<$native> for $Wrapper<$T<$n>> {
+            fn partial_cmp(&self, other: &$native) -> Option<Ordering> {
+                self.value().partial_cmp(other)
+            }
+        }
+        impl PartialOrd for $Wrapper<$T<$n>> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for $Wrapper<$T<$n>> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.value().cmp(&other.value())
+            }
+        }
+        wrapper!(derive ByteIntFmt(Debug::fmt, Display::fmt, Binary::fmt, LowerHex::fmt, Octal::fmt, UpperHex::fmt,) for $Wrapper<$T<$n>>);
+    };
+
+    // Implement a formatting trait for a byte array integer by formatting its native value
+    ( derive ByteIntFmt($( $Trait:ident :: $fn:ident , )*) for $Wrapper:ident<$T:ty> ) => { $(
+        impl $Trait for $Wrapper<$T> {
+            fn $fn(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $Trait::$fn(&self.value(), f)
+            }
+        }
+    )* };
+
+    // Implement equality against native integers that the wrapped type losslessly widens into
+    ( derive Widen $t:ident($( $wide:ident ),*) for $Wrapper:ident $g:tt ) => {
+        $( wrapper!(derive Widen $t as $wide for $Wrapper $g); )*
+    };
+    ( derive Widen $t:ident as $wide:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness)?> PartialEq<$wide> for $Wrapper<$t $(, $E)?> {
+            #[inline(always)]
+            fn eq(&self, other: &$wide) -> bool {
+                <$wide>::from(<$t>::from(*self)) == *other
+            }
+        }
+    };
+
+    // Implement a formatting trait for a wrapper type
+    ( derive Fmt $Trait:ident :: $fn:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + $Trait> $Trait for $Wrapper<T $(, $E)?> {
+            fn $fn(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                $Trait::$fn(&T::from(*self), f)
+            }
+        }
+    };
+
+    // Implement a math trait for a wrapper type
+    ( derive Math $Trait:ident :: $fn:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        // wrapper = wrapper + native
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + $Trait> $Trait<T> for $Wrapper<T $(, $E)?> where <T as $Trait>::Output: Into<$Wrapper<T $(, $E)?>> {
+            type Output = Self;
+            #[inline(always)]
+            fn $fn(self, other: T) -> Self::Output {
+                $Trait::$fn(T::from(self), other).into()
+            }
+        }
+        // wrapper = wrapper + wrapper
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + $Trait> $Trait for $Wrapper<T $(, $E)?> where <T as $Trait>::Output: Into<$Wrapper<T $(, $E)?>> {
+            type Output = Self;
+            #[inline(always)]
+            fn $fn(self, other: Self) -> Self::Output {
+                $Trait::$fn(T::from(self), T::from(other)).into()
+            }
+        }
+    };
+
+    // Implement a bitwise trait for a wrapper type, operating on the stored representation when possible.
+    // Byte order is a permutation of bytes, so it commutes with bitwise operations on integers, and
+    // a constant mask is swapped at compile time.
+    ( derive Bitwise $Trait:ident :: $fn:ident for $Wrapper:ident{$($E:ident)?} ) => {
+        // wrapper = wrapper & native
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Into<$Wrapper<T $(, $E)?>> + $Trait<Output = T> + private::StorageCmp> $Trait<T> for $Wrapper<T $(, $E)?> {
+            type Output = Self;
+            #[inline(always)]
+            fn $fn(self, other: T) -> Self::Output {
+                if T::RAW_EQ {
+                    Self::wrap($Trait::$fn(self.0, other.into().0))
+                } else {
+                    $Trait::$fn(T::from(self), other).into()
+                }
+            }
+        }
+        // wrapper = wrapper & wrapper
+        impl<$($E: Endianness,)? T: Copy + From<$Wrapper<T $(, $E)?>> + Into<$Wrapper<T $(, $E)?>> + $Trait<Output = T> + private::StorageCmp> $Trait for $Wrapper<T $(, $E)?> {
+            type Output = Self;
+            #[inline(always)]
+            fn $fn(self, other: Self) -> Self::Output {
+                if T::RAW_EQ {
+                    Self::wrap($Trait::$fn(self.0, other.0))
+                } else {
+                    $Trait::$fn(T::from(self), T::from(other)).into()
+                }
+            }
+        }
+    };
+
+    // Implement value-preserving conversion and comparison in both directions between two wrapper types
+    ( $A:ident <=> $B:ident ) => {
+        wrapper!(derive Cross $A for $B);
+        wrapper!(derive Cross $B for $A);
+    };
+
+    // Implement conversion and comparison from one wrapper type to another
+    ( derive Cross $A:ident for $B:ident ) => {
+        impl<T: Copy + From<$A<T>> + Into<$B<T>>> From<$A<T>> for $B<T> {
+            #[inline(always)]
+            fn from(other: $A<T>) -> Self {
+                T::from(other).into()
+            }
+        }
+        impl<T: Copy + From<$A<T>> + From<$B<T>> + PartialEq> PartialEq<$A<T>> for $B<T> {
+            #[inline(always)]
+            fn eq(&self, other: &$A<T>) -> bool {
+                T::eq(&T::from(*self), &T::from(*other))
+            }
+        }
+        impl<T: Copy + From<$A<T>> + From<$B<T>> + PartialOrd> PartialOrd<$A<T>> for $B<T> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &$A<T>) -> Option<Ordering> {
+                T::partial_cmp(&T::from(*self), &T::from(*other))
+            }
+        }
+    };
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Wrapper type for data that's explicitly stored in memory with the byte order `E` (`BE`, `LE` or `NE`)
+///
+/// Usually used through the `BigEndian`, `LittleEndian` and `NativeEndian` aliases,
+/// but generic code can be written over any `E: Endianness`.
+pub struct Endian<T, E>(T, PhantomData<E>);
+
+impl<T, E> Endian<T, E> {
+    #[inline(always)]
+    const fn wrap(raw: T) -> Self {
+        Self(raw, PhantomData)
+    }
+}
+
+impl<T: Primitive, E: Endianness> EndianInt<T> for Endian<T, E> {
+    type Bytes = T::Bytes;
+    #[inline(always)]
+    fn new(value: T) -> Self {
+        Self::wrap(T::from_ne_bytes(E::to_bytes(value)))
+    }
+    #[inline(always)]
+    fn value(self) -> T {
+        E::from_bytes(self.0.to_ne_bytes())
+    }
+    #[inline(always)]
+    fn from_bytes(bytes: T::Bytes) -> Self {
+        Self::wrap(T::from_ne_bytes(bytes))
+    }
+    #[inline(always)]
+    fn to_bytes(self) -> T::Bytes {
+        self.0.to_ne_bytes()
+    }
+}
+
+impl<T: Primitive, E: Endianness> Endian<T, E> {
+    /// Returns an iterator over the in-memory (storage order) bytes of the value
+    #[inline(always)]
+    pub fn bytes(self) -> <T::Bytes as IntoIterator>::IntoIter {
+        self.0.to_ne_bytes().into_iter()
+    }
+    /// Reads a value from its storage order bytes at `offset` within `buf`, returning `None` if out of bounds
+    #[inline(always)]
+    pub fn read_from(buf: &[u8], offset: usize) -> Option<Self> {
+        EndianInt::read_from(buf, offset)
+    }
+    /// Writes the storage order bytes of the value at `offset` within `buf`, returning `None` if out of bounds
+    #[inline(always)]
+    pub fn write_into(&self, buf: &mut [u8], offset: usize) -> Option<()> {
+        EndianInt::write_into(self, buf, offset)
+    }
+    /// Views a byte slice as a slice of values in place, which must be aligned and a whole number of values long
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], Error> {
+        match Self::cast_len(bytes)? {
+            0 => Ok(&[]),
+            // Safety: the alignment and length were checked, and every bit pattern is a valid primitive number
+            len => Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const Self, len) }),
+        }
+    }
+    /// Views a mutable byte slice as a slice of values in place, which must be aligned and a whole number of values long
+    pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], Error> {
+        match Self::cast_len(bytes)? {
+            0 => Ok(&mut []),
+            // Safety: as `slice_from_bytes`, and any value written back is also valid as bytes
+            len => {
+                Ok(
+                    unsafe {
+                        core::slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut Self, len)
+                    },
+                )
+            }
+        }
+    }
+    fn cast_len(bytes: &[u8]) -> Result<usize, Error> {
+        if bytes.len() % core::mem::size_of::<Self>() != 0 {
+            Err(Error::partial::<Self>(bytes.len()))
+        } else if !bytes.is_empty() && bytes.as_ptr() as usize % core::mem::align_of::<Self>() != 0
+        {
+            // An empty slice may have a dangling pointer, and is always a valid (empty) slice of values
+            Err(Error::new(ErrorKind::Misaligned).at(0))
+        } else {
+            Ok(bytes.len() / core::mem::size_of::<Self>())
+        }
+    }
+}
+
+// `Borrow<[u8]>` is intentionally not implemented, as byte-wise ordering doesn't match value ordering
+impl<T: Primitive, E: Endianness> AsRef<[u8]> for Endian<T, E> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        // Safety: primitive numbers have no padding and a byte slice has an alignment of 1
+        unsafe {
+            core::slice::from_raw_parts(self as *const Self as *const u8, core::mem::size_of::<T>())
+        }
+    }
+}
+
+impl<T: Primitive, E: Endianness> TryFrom<&[u8]> for Endian<T, E> {
+    type Error = Error;
+    #[inline(always)]
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let mut array = T::Bytes::default();
+        if array.as_ref().len() != bytes.len() {
+            return Err(Error::length(array.as_ref().len(), bytes.len()));
+        }
+        array.as_mut().copy_from_slice(bytes);
+        Ok(EndianInt::from_bytes(array))
+    }
+}
+
+impl<T: Primitive, E: Endianness> IntoIterator for Endian<T, E> {
+    type Item = u8;
+    type IntoIter = <T::Bytes as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes()
+    }
+}
+
+/// Wrapper type for data that's explicitly stored in memory as big endian
+pub type BigEndian<T> = Endian<T, BE>;
+
+/// Wrapper type for data that's explicitly stored in memory as little endian
+pub type LittleEndian<T> = Endian<T, LE>;
+
+/// Wrapper type for data that's explicitly stored in memory in the native endianess of the target
+pub type NativeEndian<T> = Endian<T, NE>;
+
+wrapper!(
+    BigEndian(to_be_bytes, from_be_bytes),
+    LittleEndian(to_le_bytes, from_le_bytes),
+    NativeEndian(to_ne_bytes, from_ne_bytes)
+);
+wrapper!(derive Native(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8, f32, f64) for Endian{E});
+#[cfg(feature = "half")]
+wrapper!(derive Native(f16, bf16) for Endian{E});
+wrapper!(derive Net(Ipv4Addr as u32, Ipv6Addr as u128) for Endian{E});
+wrapper!(derive Int(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8,) for Endian{E});
+wrapper!(derive Unsigned(usize, u128, u64, u32, u16, u8,) for Endian{E});
+
+// Untyped literals fall back to `i32` when more than one `PartialEq` impl matches, so only `i32`
+// can gain comparisons against wider integers without changing how literals are checked (other
+// types use `eq_widened`).
+wrapper!(derive Widen i32(i64, i128) for Endian{E});
+
+wrapper!(derive Generic for Endian{E});
+wrapper!(BigEndian <=> LittleEndian);
+wrapper!(BigEndian <=> NativeEndian);
+wrapper!(LittleEndian <=> NativeEndian);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Wrapper type for data stored in memory in PDP-11 middle endian (`BADC`) order
+///
+/// The value `0x0a0b_0c0d` is stored as the bytes `0b 0a 0d 0c`: little endian 16-bit words, most significant word first.
+/// This is also the "byte swapped" layout used by some Modbus devices, which extend it to 64-bit values.
+pub struct PdpEndian<T>(T);
+
+impl<T> PdpEndian<T> {
+    #[inline(always)]
+    const fn wrap(raw: T) -> Self {
+        Self(raw)
+    }
+}
+
+wrapper!(derive Permuted(u32, i32, f32) as [1, 0, 3, 2], for PdpEndian);
+wrapper!(derive Permuted(u64, i64, f64) as [1, 0, 3, 2, 5, 4, 7, 6], for PdpEndian);
+wrapper!(derive Bytes(u64, u32, i64, i32, f32, f64,) for PdpEndian);
+wrapper!(derive ByteSlice(u64, u32, i64, i32, f32, f64,) for PdpEndian);
+wrapper!(derive Int(u64, u32, i64, i32,) for PdpEndian{});
+wrapper!(derive Unsigned(u64, u32,) for PdpEndian{});
+wrapper!(derive Widen i32(i64, i128) for PdpEndian{});
+wrapper!(derive Generic for PdpEndian{});
+wrapper!(PdpEndian <=> BigEndian);
+wrapper!(PdpEndian <=> LittleEndian);
+wrapper!(PdpEndian <=> NativeEndian);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Wrapper type for data stored in memory as big endian 16-bit words, least significant word first (`CDAB`)
+///
+/// The value `0x0a0b_0c0d` is stored as the bytes `0c 0d 0a 0b`, as commonly used by Modbus devices and PLCs.
+pub struct WordSwapped<T>(T);
+
+impl<T> WordSwapped<T> {
+    #[inline(always)]
+    const fn wrap(raw: T) -> Self {
+        Self(raw)
+    }
+}
+
+wrapper!(derive Permuted(u32, i32, f32) as [2, 3, 0, 1], for WordSwapped);
+wrapper!(derive Permuted(u64, i64, f64) as [6, 7, 4, 5, 2, 3, 0, 1], for WordSwapped);
+wrapper!(derive Bytes(u64, u32, i64, i32, f32, f64,) for WordSwapped);
+wrapper!(derive ByteSlice(u64, u32, i64, i32, f32, f64,) for WordSwapped);
+wrapper!(derive Int(u64, u32, i64, i32,) for WordSwapped{});
+wrapper!(derive Unsigned(u64, u32,) for WordSwapped{});
+wrapper!(derive Widen i32(i64, i128) for WordSwapped{});
+wrapper!(derive Generic for WordSwapped{});
+wrapper!(WordSwapped <=> BigEndian);
+wrapper!(WordSwapped <=> LittleEndian);
+wrapper!(WordSwapped <=> NativeEndian);
+wrapper!(WordSwapped <=> PdpEndian);
+
+/// Element-wise conversion of an array of wrappers (e.g. `[BEu32; N]`) to native values
+pub trait MapToNative<W, const N: usize> {
+    /// Converts each element to its native value
+    fn map_to_native<T>(self) -> [T; N]
+    where
+        W: Into<T>;
+}
+
+impl<W, const N: usize> MapToNative<W, N> for [W; N] {
+    fn map_to_native<T>(self) -> [T; N]
+    where
+        W: Into<T>,
+    {
+        self.map(W::into)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Byte order selected at runtime, e.g. from a file header
+pub enum ByteOrder {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+impl ByteOrder {
+    /// The native byte order of the target
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: ByteOrder = ByteOrder::Big;
+    /// The native byte order of the target
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: ByteOrder = ByteOrder::Little;
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// Bit numbering used when accessing bit fields, as hardware datasheets and protocols differ
+pub enum BitOrder {
+    /// Bit 0 is the most significant bit
+    Msb0,
+    /// Bit 0 is the least significant bit
+    Lsb0,
+}
+
+impl BitOrder {
+    // Returns the right shift that moves a `len` bit field starting at `start` to the least significant bits
+    fn shift(self, start: u32, len: u32, bits: u32) -> u32 {
+        assert!(
+            len > 0 && start < bits && len <= bits - start,
+            "bit field out of range"
+        );
+        match self {
+            BitOrder::Msb0 => bits - start - len,
+            BitOrder::Lsb0 => start,
+        }
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+
+    // How wrapped values can be compared without converting them to native first.
+    //
+    // `RAW_EQ`: equality can be decided on the stored representation, which holds for integers but
+    // not for floats (`NaN`, signed zero) or user-defined `PartialEq`.
+    // `RAW_ORD`: the type is a single unsigned integer, so ordering can be decided on its stored
+    // bytes, see `cmp_stored`.
+    pub trait StorageCmp {
+        const RAW_EQ: bool;
+        const RAW_ORD: bool = false;
+    }
+
+    // Compares two stored unsigned integers byte by byte, most significant byte first
+    #[inline(always)]
+    pub fn cmp_stored<T: StorageCmp>(a: &T, b: &T, order: super::ByteOrder) -> super::Ordering {
+        debug_assert!(T::RAW_ORD);
+        let size = core::mem::size_of::<T>();
+        // SAFETY: `RAW_ORD` is only set for unsigned integers, which have no padding bytes
+        let (a, b) = unsafe {
+            (
+                core::slice::from_raw_parts(a as *const T as *const u8, size),
+                core::slice::from_raw_parts(b as *const T as *const u8, size),
+            )
+        };
+        match order {
+            super::ByteOrder::Big => a.cmp(b),
+            super::ByteOrder::Little => a.iter().rev().cmp(b.iter().rev()),
+        }
+    }
+}
+
+macro_rules! storage_cmp {
+    ( $raw_eq:expr, $raw_ord:expr => $( $t:ty ),* ) => { $(
+        impl private::StorageCmp for $t {
+            const RAW_EQ: bool = $raw_eq;
+            const RAW_ORD: bool = $raw_ord;
+        }
+    )* };
+}
+
+storage_cmp!(true, true => usize, u128, u64, u32, u16, u8);
+storage_cmp!(true, false => isize, i128, i64, i32, i16, i8);
+storage_cmp!(true, true => NonZeroUsize, NonZeroU128, NonZeroU64, NonZeroU32, NonZeroU16, NonZeroU8);
+storage_cmp!(true, false => NonZeroIsize, NonZeroI128, NonZeroI64, NonZeroI32, NonZeroI16, NonZeroI8);
+storage_cmp!(false, false => f64, f32);
+#[cfg(feature = "half")]
+storage_cmp!(false, false => f16, bf16);
+
+impl<T: private::StorageCmp> private::StorageCmp for Wrapping<T> {
+    const RAW_EQ: bool = T::RAW_EQ;
+    const RAW_ORD: bool = T::RAW_ORD;
+}
+
+impl<T: private::StorageCmp> private::StorageCmp for Saturating<T> {
+    const RAW_EQ: bool = T::RAW_EQ;
+    const RAW_ORD: bool = T::RAW_ORD;
+}
+
+impl<T: private::StorageCmp, const N: usize> private::StorageCmp for [T; N] {
+    const RAW_EQ: bool = T::RAW_EQ;
+}
+
+impl<T: EndianConvert> private::StorageCmp for T {
+    const RAW_EQ: bool = false;
+}
+
+/// A primitive type that can be stored in the endian wrappers, for use in generic code
+///
+/// This trait is sealed and implemented for all integer and floating-point types.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a primitive number type",
+    label = "only integer and floating-point types can be stored directly in the endian wrappers",
+    note = "other types can be stored using `EndianConvert`, `EndianRepr` or one of the storage types such as `Bool<T>`"
+)]
+pub trait Primitive:
+    private::Sealed + Copy + PartialEq + PartialOrd + Debug + Display + Default
+{
+    /// The byte array representation of the type
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]> + Default + IntoIterator<Item = u8>;
+    /// Returns the big-endian byte representation
+    fn to_be_bytes(self) -> Self::Bytes;
+    /// Creates a value from its big-endian byte representation
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+    /// Returns the little-endian byte representation
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// Creates a value from its little-endian byte representation
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    /// Returns the native-endian byte representation
+    fn to_ne_bytes(self) -> Self::Bytes;
+    /// Creates a value from its native-endian byte representation
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+}
+
+// 128-bit byte order conversions, swapping each 64-bit half and exchanging them. A single 128-bit
+// swap lowers to poor code on some 32-bit and Thumb targets, while this form maps onto native
+// 64-bit (or paired 32-bit `rev`) swaps everywhere.
+mod wide {
+    #[inline(always)]
+    const fn swap(value: u128) -> u128 {
+        ((value as u64).swap_bytes() as u128) << 64 | ((value >> 64) as u64).swap_bytes() as u128
+    }
+    #[inline(always)]
+    const fn to_be(value: u128) -> u128 {
+        if cfg!(target_endian = "big") {
+            value
+        } else {
+            swap(value)
+        }
+    }
+    #[inline(always)]
+    const fn to_le(value: u128) -> u128 {
+        if cfg!(target_endian = "little") {
+            value
+        } else {
+            swap(value)
+        }
+    }
+    #[inline(always)]
+    pub const fn to_be_bytes(value: u128) -> [u8; 16] {
+        to_be(value).to_ne_bytes()
+    }
+    #[inline(always)]
+    pub const fn from_be_bytes(bytes: [u8; 16]) -> u128 {
+        to_be(u128::from_ne_bytes(bytes))
+    }
+    #[inline(always)]
+    pub const fn to_le_bytes(value: u128) -> [u8; 16] {
+        to_le(value).to_ne_bytes()
+    }
+    #[inline(always)]
+    pub const fn from_le_bytes(bytes: [u8; 16]) -> u128 {
+        to_le(u128::from_ne_bytes(bytes))
+    }
+    #[inline(always)]
+    pub const fn to_ne_bytes(value: u128) -> [u8; 16] {
+        value.to_ne_bytes()
+    }
+    #[inline(always)]
+    pub const fn from_ne_bytes(bytes: [u8; 16]) -> u128 {
+        u128::from_ne_bytes(bytes)
+    }
+}
+
+macro_rules! primitive {
+    ( wide $( $t:ident ),* ) => { $(
+        impl private::Sealed for $t {}
+        impl Primitive for $t {
+            type Bytes = [u8; 16];
+            #[inline(always)]
+            fn to_be_bytes(self) -> Self::Bytes {
+                wide::to_be_bytes(self as u128)
+            }
+            #[inline(always)]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                wide::from_be_bytes(bytes) as $t
+            }
+            #[inline(always)]
+            fn to_le_bytes(self) -> Self::Bytes {
+                wide::to_le_bytes(self as u128)
+            }
+            #[inline(always)]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                wide::from_le_bytes(bytes) as $t
+            }
+            #[inline(always)]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                <$t>::to_ne_bytes(self)
+            }
+            #[inline(always)]
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_ne_bytes(bytes)
+            }
+        }
+    )* };
+    ( $( $t:ident ),* ) => { $(
+        impl private::Sealed for $t {}
+        impl Primitive for $t {
+            type Bytes = [u8; core::mem::size_of::<$t>()];
+            #[inline(always)]
+            fn to_be_bytes(self) -> Self::Bytes {
+                <$t>::to_be_bytes(self)
+            }
+            #[inline(always)]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_be_bytes(bytes)
+            }
+            #[inline(always)]
+            fn to_le_bytes(self) -> Self::Bytes {
+                <$t>::to_le_bytes(self)
+            }
+            #[inline(always)]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_le_bytes(bytes)
+            }
+            #[inline(always)]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                <$t>::to_ne_bytes(self)
+            }
+            #[inline(always)]
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                <$t>::from_ne_bytes(bytes)
+            }
+        }
+    )* };
+}
+
+primitive!(usize, u64, u32, u16, u8, isize, i64, i32, i16, i8, f64, f32);
+#[cfg(feature = "half")]
+primitive!(f16, bf16);
+primitive!(wide u128, i128);
+
+/// The API shared by the endian wrappers of a primitive type, for use in generic code
+pub trait EndianInt<T: Primitive>: Copy {
+    /// The byte array representation of the value
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]> + Default;
+    /// Creates a value from a native value
+    fn new(value: T) -> Self;
+    /// Returns the native value
+    fn value(self) -> T;
+    /// Creates a value from its in-memory (storage order) byte representation
+    fn from_bytes(bytes: Self::Bytes) -> Self;
+    /// Returns the in-memory (storage order) byte representation of the value
+    fn to_bytes(self) -> Self::Bytes;
+    /// Reads a value from its storage order bytes at `offset` within `buf`, returning `None` if out of bounds
+    #[inline(always)]
+    fn read_from(buf: &[u8], offset: usize) -> Option<Self> {
+        let mut bytes = Self::Bytes::default();
+        let len = bytes.as_ref().len();
+        bytes
+            .as_mut()
+            .copy_from_slice(buf.get(offset..)?.get(..len)?);
+        Some(Self::from_bytes(bytes))
+    }
+    /// Writes the storage order bytes of the value at `offset` within `buf`, returning `None` if out of bounds
+    #[inline(always)]
+    fn write_into(&self, buf: &mut [u8], offset: usize) -> Option<()> {
+        let bytes = self.to_bytes();
+        let len = bytes.as_ref().len();
+        buf.get_mut(offset..)?
+            .get_mut(..len)?
+            .copy_from_slice(bytes.as_ref());
+        Some(())
+    }
+}
+
+/// A value with a fixed-size storage order byte representation, for reading from and writing to byte buffers
+///
+/// This is implemented for the endian wrappers of primitive types, and for `u8` and `i8`.
+pub trait EndianValue: Copy {
+    /// The byte array representation of the value
+    type Bytes: Copy + AsRef<[u8]> + AsMut<[u8]> + Default;
+    /// Creates a value from its in-memory (storage order) byte representation
+    fn from_storage(bytes: Self::Bytes) -> Self;
+    /// Returns the in-memory (storage order) byte representation of the value
+    fn to_storage(self) -> Self::Bytes;
+}
+
+impl<T: Primitive, E: Endianness> EndianValue for Endian<T, E> {
+    type Bytes = T::Bytes;
+    #[inline(always)]
+    fn from_storage(bytes: T::Bytes) -> Self {
+        EndianInt::from_bytes(bytes)
+    }
+    #[inline(always)]
+    fn to_storage(self) -> T::Bytes {
+        EndianInt::to_bytes(self)
+    }
+}
+
+impl EndianValue for u8 {
+    type Bytes = [u8; 1];
+    #[inline(always)]
+    fn from_storage(bytes: [u8; 1]) -> Self {
+        bytes[0]
+    }
+    #[inline(always)]
+    fn to_storage(self) -> [u8; 1] {
+        [self]
+    }
+}
+
+impl EndianValue for i8 {
+    type Bytes = [u8; 1];
+    #[inline(always)]
+    fn from_storage(bytes: [u8; 1]) -> Self {
+        bytes[0] as i8
+    }
+    #[inline(always)]
+    fn to_storage(self) -> [u8; 1] {
+        [self as u8]
+    }
+}
+
+/// An unsigned value used as a length field, e.g. by `Tlv`
+pub trait EndianLength: EndianValue {
+    /// Returns the length, or `None` if it doesn't fit in a `usize`
+    fn to_len(self) -> Option<usize>;
+    /// Creates a length field, or returns `None` if `len` doesn't fit
+    fn from_len(len: usize) -> Option<Self>;
+}
+
+impl EndianLength for u8 {
+    fn to_len(self) -> Option<usize> {
+        Some(self.into())
+    }
+    fn from_len(len: usize) -> Option<Self> {
+        u8::try_from(len).ok()
+    }
+}
+
+macro_rules! endian_length {
+    ( $( $t:ident ),* ) => { $(
+        impl<E: Endianness> EndianLength for Endian<$t, E> {
+            fn to_len(self) -> Option<usize> {
+                usize::try_from(EndianInt::value(self)).ok()
+            }
+            fn from_len(len: usize) -> Option<Self> {
+                Some(EndianInt::new(<$t>::try_from(len).ok()?))
+            }
+        }
+    )* };
+}
+
+endian_length!(usize, u128, u64, u32, u16, u8);
+
+/// A byte order known at compile time, for writing code that is generic over byte order
+///
+/// ```rust
+/// use storage_endian::{EndianInt, Endianness, BE, LE};
+///
+/// fn parse<E: Endianness>(buf: &[u8]) -> Option<(u16, u32)> {
+///     let kind = E::Wrapper::<u16>::read_from(buf, 0)?.value();
+///     let len = E::Wrapper::<u32>::read_from(buf, 2)?.value();
+///     Some((kind, len))
+/// }
+///
+/// assert_eq!(parse::<BE>(&[0, 1, 0, 0, 0, 2]), Some((1, 2)));
+/// assert_eq!(parse::<LE>(&[1, 0, 2, 0, 0, 0]), Some((1, 2)));
+/// ```
+pub trait Endianness: private::Sealed + Copy {
+    /// The byte order, for use at runtime
+    const ORDER: ByteOrder;
+    /// The wrapper type storing values in this byte order
+    type Wrapper<T: Primitive>: EndianInt<T>;
+    /// Returns the byte representation of a value in this byte order
+    fn to_bytes<T: Primitive>(value: T) -> T::Bytes;
+    /// Creates a value from its byte representation in this byte order
+    fn from_bytes<T: Primitive>(bytes: T::Bytes) -> T;
+}
+
+#[derive(Copy, Clone, Debug)]
+/// Marker for big-endian byte order, using `BigEndian`
+pub struct BE;
+
+#[derive(Copy, Clone, Debug)]
+/// Marker for little-endian byte order, using `LittleEndian`
+pub struct LE;
+
+#[derive(Copy, Clone, Debug)]
+/// Marker for the native byte order of the target, using `NativeEndian`
+pub struct NE;
+
+impl private::Sealed for BE {}
+impl Endianness for BE {
+    const ORDER: ByteOrder = ByteOrder::Big;
+    type Wrapper<T: Primitive> = BigEndian<T>;
+    #[inline(always)]
+    fn to_bytes<T: Primitive>(value: T) -> T::Bytes {
+        value.to_be_bytes()
+    }
+    #[inline(always)]
+    fn from_bytes<T: Primitive>(bytes: T::Bytes) -> T {
+        T::from_be_bytes(bytes)
+    }
+}
+
+impl private::Sealed for LE {}
+impl Endianness for LE {
+    const ORDER: ByteOrder = ByteOrder::Little;
+    type Wrapper<T: Primitive> = LittleEndian<T>;
+    #[inline(always)]
+    fn to_bytes<T: Primitive>(value: T) -> T::Bytes {
+        value.to_le_bytes()
+    }
+    #[inline(always)]
+    fn from_bytes<T: Primitive>(bytes: T::Bytes) -> T {
+        T::from_le_bytes(bytes)
+    }
+}
+
+impl private::Sealed for NE {}
+impl Endianness for NE {
+    const ORDER: ByteOrder = ByteOrder::NATIVE;
+    type Wrapper<T: Primitive> = NativeEndian<T>;
+    #[inline(always)]
+    fn to_bytes<T: Primitive>(value: T) -> T::Bytes {
+        value.to_ne_bytes()
+    }
+    #[inline(always)]
+    fn from_bytes<T: Primitive>(bytes: T::Bytes) -> T {
+        T::from_ne_bytes(bytes)
+    }
+}
+
+/// Conversion between a user-defined newtype (e.g. `struct BlockNo(u64)`) and the primitive type it wraps
+///
+/// The `endian_convert!` macro implements this trait for tuple newtypes, along with the conversions that
+/// allow the endian wrappers to provide comparison, arithmetic and formatting based on the newtype's own traits.
+///
+/// ```rust
+/// use storage_endian::{endian_convert, BigEndian};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+/// struct BlockNo(u64);
+/// endian_convert!(BlockNo(u64));
+///
+/// let block = BigEndian::<BlockNo>::new(BlockNo(7));
+/// assert_eq!(block.to_bytes(), [0, 0, 0, 0, 0, 0, 0, 7]);
+/// assert_eq!(block, BlockNo(7));
+/// assert!(block < BlockNo(8));
+/// ```
+pub trait EndianConvert: Copy {
+    /// The primitive type wrapped by the newtype
+    type Repr: Primitive;
+    /// Returns the wrapped primitive value
+    fn to_repr(self) -> Self::Repr;
+    /// Creates a value from the wrapped primitive value
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+impl<T: EndianConvert, E: Endianness> Endian<T, E> {
+    /// Creates a value from a native value
+    #[inline(always)]
+    pub fn new(value: T) -> Self {
+        Self::from_bytes(E::to_bytes(value.to_repr()))
+    }
+    /// Returns the native value
+    #[inline(always)]
+    pub fn value(self) -> T {
+        T::from_repr(E::from_bytes(self.to_bytes()))
+    }
+    /// Creates a value from its in-memory (storage order) byte representation
+    #[inline(always)]
+    pub fn from_bytes(bytes: <T::Repr as Primitive>::Bytes) -> Self {
+        Self::wrap(T::from_repr(T::Repr::from_ne_bytes(bytes)))
+    }
+    /// Returns the in-memory (storage order) byte representation of the value
+    #[inline(always)]
+    pub fn to_bytes(self) -> <T::Repr as Primitive>::Bytes {
+        self.0.to_repr().to_ne_bytes()
+    }
+}
+
+impl<T: EndianConvert, E: Endianness> From<T> for Endian<T, E> {
+    #[inline(always)]
+    fn from(other: T) -> Self {
+        Self::new(other)
+    }
+}
+
+/// Implements `EndianConvert` for tuple newtypes over primitive types, so they can be stored in the endian wrappers
+///
+/// See `EndianConvert` for an example.
+#[macro_export]
+macro_rules! endian_convert {
+    ( $( $T:ident($repr:ty) ),* $(,)? ) => { $(
+        impl $crate::EndianConvert for $T {
+            type Repr = $repr;
+            fn to_repr(self) -> $repr {
+                self.0
+            }
+            fn from_repr(repr: $repr) -> Self {
+                $T(repr)
+            }
+        }
+        impl<E: $crate::Endianness> From<$crate::Endian<$T, E>> for $T {
+            #[inline(always)]
+            fn from(other: $crate::Endian<$T, E>) -> Self {
+                other.value()
+            }
+        }
+    )* };
+}
+
+/// Creates a `BigEndian` value from a suffixed literal or constant expression, evaluated at compile time
+///
+/// ```
+/// use storage_endian::{be, BEu32};
+///
+/// const MAGIC: BEu32 = be!(0x1337_beefu32);
+/// assert_eq!(MAGIC.to_bytes(), [0x13, 0x37, 0xbe, 0xef]);
+/// ```
+#[macro_export]
+macro_rules! be {
+    ( $value:expr ) => {
+        const { $crate::Literal($value).be() }
+    };
+}
+
+/// Creates a `LittleEndian` value from a suffixed literal or constant expression, evaluated at compile time
+///
+/// ```
+/// use storage_endian::{le, LEu16};
+///
+/// const VERSION: LEu16 = le!(42u16);
+/// assert_eq!(VERSION.to_bytes(), [42, 0]);
+/// ```
+#[macro_export]
+macro_rules! le {
+    ( $value:expr ) => {
+        const { $crate::Literal($value).le() }
+    };
+}
+
+// Selects the wrapper type for `be!` and `le!` from the type of the value
+#[doc(hidden)]
+pub struct Literal<T>(pub T);
+
+macro_rules! literal {
+    ( $( $t:ident ),* ) => { $(
+        impl Literal<$t> {
+            pub const fn be(self) -> BigEndian<$t> {
+                BigEndian::<$t>::new(self.0)
+            }
+            pub const fn le(self) -> LittleEndian<$t> {
+                LittleEndian::<$t>::new(self.0)
+            }
+        }
+    )* };
+}
+
+literal!(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8, f32, f64);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Wrapper type for data stored in memory in a byte order that's only known at runtime
+///
+/// The byte order isn't stored, so a single `#[repr(C)]` struct can describe formats like TIFF or ELF
+/// which declare their byte order in a header, with the order passed to each access.
+pub struct DynamicEndian<T>(T);
+
+wrapper!(derive Dynamic(usize, u128, u64, u32, u16, u8, isize, i128, i64, i32, i16, i8, f32, f64));
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for an unsigned integer of `N` bytes (from 1 to 16), for use with the endian wrappers
+pub struct UInt<const N: usize>([u8; N]);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a signed integer of `N` bytes (from 1 to 16), for use with the endian wrappers
+pub struct Int<const N: usize>([u8; N]);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a boolean encoded as an integer, for use with the endian wrappers
+///
+/// Wrapped booleans compare and hash by their decoded value, so any non-zero encoding is equal to
+/// `true`, even though the stored bytes differ. Use `to_bytes` or `checked_value` to tell them apart.
+pub struct Bool<T>(T);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a UTF-32 code point, for use with the endian wrappers
+pub struct Char32(u32);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a packed binary-coded decimal number (two digits per byte), for use with the endian wrappers
+pub struct Bcd<T>(T);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a one's complement signed integer, for use with the endian wrappers
+pub struct OnesComplement<T>(T);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a sign-magnitude signed integer, for use with the endian wrappers
+pub struct SignMagnitude<T>(T);
+
+macro_rules! signed_encoding {
+    ( $( $t:ident ),* ) => { $(
+        impl OnesComplement<$t> {
+            // Inverts the bits of the magnitude, which is one less in two's complement
+            const fn encode_negative(value: $t) -> $t {
+                value - 1
+            }
+            const fn decode_negative(bits: $t) -> $t {
+                bits + 1
+            }
+        }
+        impl SignMagnitude<$t> {
+            // Sets the sign bit on the magnitude
+            const fn encode_negative(value: $t) -> $t {
+                -value | <$t>::MIN
+            }
+            const fn decode_negative(bits: $t) -> $t {
+                -(bits & <$t>::MAX)
+            }
+        }
+    )* };
+}
+
+signed_encoding!(i128, i64, i32, i16, i8);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for an optional integer where the sentinel `S` means `None`, for use with the endian wrappers
+///
+/// The sentinel is truncated to the stored type, so `Sentinel<u32, 0xffff_ffff>` and
+/// `Sentinel<i32, 0xffff_ffff>` both use all bits set (`-1` for `i32`).
+pub struct Sentinel<T, const S: u128>(T);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a Unix timestamp in whole seconds, for use with the endian wrappers
+pub struct UnixTime<T>(T);
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+/// Storage for a fixed-point number with `F` fractional bits, for use with the endian wrappers
+///
+/// `Fixed<i32, 16>` is the Q16.16 format, where the stored integer is the value multiplied by `2^16`.
+pub struct Fixed<T, const F: u32>(T);
+
+/// Conversion between a type (such as a fieldless enum) and its integer representation
+///
+/// Implementing this allows the type to be stored in the endian wrappers as `BigEndian<Enum<T>>`,
+/// with infallible encoding and fallible decoding.
+///
+/// ```rust
+/// use storage_endian::{BigEndian, Enum, EndianRepr};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// #[repr(u16)]
+/// enum Opcode {
+///     Read = 1,
+///     Write = 2,
+/// }
+/// impl EndianRepr for Opcode {
+///     type Repr = u16;
+///     fn to_repr(self) -> u16 {
+///         self as u16
+///     }
+///     fn from_repr(repr: u16) -> Option<Self> {
+///         match repr {
+///             1 => Some(Opcode::Read),
+///             2 => Some(Opcode::Write),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let op = BigEndian::<Enum<Opcode>>::new(Opcode::Write);
+/// assert_eq!(op, Opcode::Write);
+/// assert_eq!(op.checked_value(), Some(Opcode::Write));
+/// ```
+pub trait EndianRepr: Copy {
+    /// The integer type used to store the value
+    type Repr: Copy;
+    /// Returns the integer representation of the value
+    fn to_repr(self) -> Self::Repr;
+    /// Returns the value for an integer representation, or `None` if it isn't valid
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+#[repr(transparent)]
+/// Storage for an `EndianRepr` type as its integer representation, for use with the endian wrappers
+pub struct Enum<E: EndianRepr>(E::Repr);
+
+impl<E: EndianRepr> Clone for Enum<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<E: EndianRepr> Copy for Enum<E> {}
+
+// Odd-width integer storage aliases
+pub type U24 = UInt<3>;
+pub type U40 = UInt<5>;
+pub type U48 = UInt<6>;
+pub type U56 = UInt<7>;
+pub type I24 = Int<3>;
+pub type I40 = Int<5>;
+pub type I48 = Int<6>;
+pub type I56 = Int<7>;
+
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// The kind of failure described by an `Error`
+pub enum ErrorKind {
+    /// The input ended before the data was complete
+    Truncated,
+    /// A magic number or signature didn't match
+    BadMagic,
+    /// A value is outside the range allowed for it
+    OutOfRange,
+    /// The data isn't aligned for the type being accessed
+    Misaligned,
+    /// The input was longer than the data being read
+    TooLong,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// A parsing error with its kind, and the byte offset and expected and actual values where known
+///
+/// This is returned by the fallible byte and range conversions, slice casts, readers, writers and views.
+/// For `OutOfRange` errors, the expected and actual values are the maximum allowed and the value given.
+/// ```
+/// use storage_endian::{EndianReader, Error, ErrorKind, LEu32};
+///
+/// fn parse(bytes: &[u8]) -> Result<u32, Error> {
+///     let mut reader = EndianReader::new(bytes);
+///     reader.expect_magic(b"MZ")?;
+///     Ok(reader.read::<LEu32>()?.value())
+/// }
+///
+/// assert_eq!(parse(b"MZ\x01\x00\x00\x00"), Ok(1));
+/// let error = parse(b"MZ\x01").unwrap_err();
+/// assert_eq!((error.kind(), error.offset()), (ErrorKind::Truncated, Some(2)));
+/// // For truncation, the expected and actual values are the bytes needed and the bytes left
+/// assert_eq!((error.expected(), error.actual()), (Some(4), Some(1)));
+/// let error = parse(b"PK").unwrap_err();
+/// assert_eq!(error.kind(), ErrorKind::BadMagic);
+/// assert_eq!((error.expected(), error.actual()), (Some(0x4d5a), Some(0x504b)));
+/// ```
+pub struct Error {
+    kind: ErrorKind,
+    offset: Option<usize>,
+    values: Option<(u64, u64)>,
+}
+
+impl Error {
+    /// Creates an error of the given kind
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            offset: None,
+            values: None,
+        }
+    }
+
+    /// Creates a `Truncated` error for `needed` bytes at `offset`, with only `remaining` left
+    pub(crate) const fn truncated(offset: usize, needed: usize, remaining: usize) -> Self {
+        Self::new(ErrorKind::Truncated)
+            .at(offset)
+            .values(needed as u64, remaining as u64)
+    }
+
+    /// Creates an error for `actual` bytes given where exactly `expected` were required
+    pub(crate) const fn length(expected: usize, actual: usize) -> Self {
+        let kind = if actual < expected {
+            ErrorKind::Truncated
+        } else {
+            ErrorKind::TooLong
+        };
+        Self::new(kind).values(expected as u64, actual as u64)
+    }
+
+    /// Creates an error for a slice of `len` bytes ending part way through a `V`
+    pub(crate) const fn partial<V>(len: usize) -> Self {
+        let remaining = len % core::mem::size_of::<V>();
+        Self::truncated(len - remaining, core::mem::size_of::<V>(), remaining)
+    }
+
+    /// Creates an `OutOfRange` error for `actual` where at most `max` is allowed, recording both as the
+    /// expected and actual values if they fit in a `u64`
+    pub(crate) fn out_of_range<T>(max: T, actual: T) -> Self
+    where
+        u64: TryFrom<T>,
+    {
+        let error = Self::new(ErrorKind::OutOfRange);
+        match (u64::try_from(max), u64::try_from(actual)) {
+            (Ok(max), Ok(actual)) => error.values(max, actual),
+            _ => error,
+        }
+    }
+
+    /// Sets the byte offset at which the error occurred
+    pub const fn at(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the expected and actual values (e.g. lengths or magic numbers)
+    pub const fn values(mut self, expected: u64, actual: u64) -> Self {
+        self.values = Some((expected, actual));
+        self
+    }
+
+    /// Returns the kind of error
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte offset at which the error occurred, if known
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns the expected value, if known
+    pub const fn expected(&self) -> Option<u64> {
+        match self.values {
+            Some((expected, _)) => Some(expected),
+            None => None,
+        }
+    }
+
+    /// Returns the actual value, if known
+    pub const fn actual(&self) -> Option<u64> {
+        match self.values {
+            Some((_, actual)) => Some(actual),
+            None => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.kind {
+            ErrorKind::Truncated => write!(f, "data truncated")?,
+            ErrorKind::BadMagic => write!(f, "bad magic number")?,
+            ErrorKind::OutOfRange => write!(f, "value out of range")?,
+            ErrorKind::Misaligned => write!(f, "data misaligned")?,
+            ErrorKind::TooLong => write!(f, "unexpected trailing data")?,
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {}", offset)?;
+        }
+        match (self.kind, self.values) {
+            (ErrorKind::BadMagic, Some((expected, actual))) => {
+                write!(f, " (expected {:#x}, got {:#x})", expected, actual)
+            }
+            (ErrorKind::OutOfRange, Some((max, actual))) => {
+                write!(f, " (maximum {}, got {})", max, actual)
+            }
+            (_, Some((expected, actual))) => write!(f, " (expected {}, got {})", expected, actual),
+            (_, None) => Ok(()),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+// Big-endian type aliases
+pub type BEu128 = BigEndian<u128>;
+pub type BEu64 = BigEndian<u64>;
+pub type BEu32 = BigEndian<u32>;
+pub type BEu16 = BigEndian<u16>;
+pub type BEu8 = BigEndian<u8>;
+pub type BEu56 = BigEndian<U56>;
+pub type BEu48 = BigEndian<U48>;
+pub type BEu40 = BigEndian<U40>;
+pub type BEu24 = BigEndian<U24>;
+pub type BEi128 = BigEndian<i128>;
+pub type BEi64 = BigEndian<i64>;
+pub type BEi32 = BigEndian<i32>;
+pub type BEi16 = BigEndian<i16>;
+pub type BEi8 = BigEndian<i8>;
+pub type BEi56 = BigEndian<I56>;
+pub type BEi48 = BigEndian<I48>;
+pub type BEi40 = BigEndian<I40>;
+pub type BEi24 = BigEndian<I24>;
+pub type BEf64 = BigEndian<f64>;
+pub type BEf32 = BigEndian<f32>;
+#[cfg(feature = "half")]
+pub type BEf16 = BigEndian<f16>;
+#[cfg(feature = "half")]
+pub type BEbf16 = BigEndian<bf16>;
+pub type BEbool64 = BigEndian<Bool<u64>>;
+pub type BEbool32 = BigEndian<Bool<u32>>;
+pub type BEbool16 = BigEndian<Bool<u16>>;
+pub type BEbool8 = BigEndian<Bool<u8>>;
+pub type BEchar32 = BigEndian<Char32>;
+pub type BEbcd64 = BigEndian<Bcd<u64>>;
+pub type BEbcd32 = BigEndian<Bcd<u32>>;
+pub type BEbcd16 = BigEndian<Bcd<u16>>;
+pub type BEbcd8 = BigEndian<Bcd<u8>>;
+pub type BEtime64 = BigEndian<UnixTime<i64>>;
+pub type BEtime32 = BigEndian<UnixTime<u32>>;
+pub type BEq16_16 = BigEndian<Fixed<i32, 16>>;
+pub type BEq8_24 = BigEndian<Fixed<i32, 24>>;
+pub type BEq1_31 = BigEndian<Fixed<i32, 31>>;
+pub type BEq2_14 = BigEndian<Fixed<i16, 14>>;
+pub type BEq1_15 = BigEndian<Fixed<i16, 15>>;
+
+// Little-endian type aliases
+pub type LEu128 = LittleEndian<u128>;
+pub type LEu64 = LittleEndian<u64>;
+pub type LEu32 = LittleEndian<u32>;
+pub type LEu16 = LittleEndian<u16>;
+pub type LEu8 = LittleEndian<u8>;
+pub type LEu56 = LittleEndian<U56>;
+pub type LEu48 = LittleEndian<U48>;
+pub type LEu40 = LittleEndian<U40>;
+pub type LEu24 = LittleEndian<U24>;
+pub type LEi128 = LittleEndian<i128>;
+pub type LEi64 = LittleEndian<i64>;
+pub type LEi32 = LittleEndian<i32>;
+pub type LEi16 = LittleEndian<i16>;
+pub type LEi8 = LittleEndian<i8>;
+pub type LEi56 = LittleEndian<I56>;
+pub type LEi48 = LittleEndian<I48>;
+pub type LEi40 = LittleEndian<I40>;
+pub type LEi24 = LittleEndian<I24>;
+pub type LEf64 = LittleEndian<f64>;
+pub type LEf32 = LittleEndian<f32>;
+#[cfg(feature = "half")]
+pub type LEf16 = LittleEndian<f16>;
+#[cfg(feature = "half")]
+pub type LEbf16 = LittleEndian<bf16>;
+pub type LEbool64 = LittleEndian<Bool<u64>>;
+pub type LEbool32 = LittleEndian<Bool<u32>>;
+pub type LEbool16 = LittleEndian<Bool<u16>>;
+pub type LEbool8 = LittleEndian<Bool<u8>>;
+pub type LEchar32 = LittleEndian<Char32>;
+pub type LEbcd64 = LittleEndian<Bcd<u64>>;
+pub type LEbcd32 = LittleEndian<Bcd<u32>>;
+pub type LEbcd16 = LittleEndian<Bcd<u16>>;
+pub type LEbcd8 = LittleEndian<Bcd<u8>>;
+pub type LEtime64 = LittleEndian<UnixTime<i64>>;
+pub type LEtime32 = LittleEndian<UnixTime<u32>>;
+pub type LEq16_16 = LittleEndian<Fixed<i32, 16>>;
+pub type LEq8_24 = LittleEndian<Fixed<i32, 24>>;
+pub type LEq1_31 = LittleEndian<Fixed<i32, 31>>;
+pub type LEq2_14 = LittleEndian<Fixed<i16, 14>>;
+pub type LEq1_15 = LittleEndian<Fixed<i16, 15>>;
+
+// Native-endian type aliases
+pub type NEu128 = NativeEndian<u128>;
+pub type NEu64 = NativeEndian<u64>;
+pub type NEu32 = NativeEndian<u32>;
+pub type NEu16 = NativeEndian<u16>;
+pub type NEu8 = NativeEndian<u8>;
+pub type NEu56 = NativeEndian<U56>;
+pub type NEu48 = NativeEndian<U48>;
+pub type NEu40 = NativeEndian<U40>;
+pub type NEu24 = NativeEndian<U24>;
+pub type NEi128 = NativeEndian<i128>;
+pub type NEi64 = NativeEndian<i64>;
+pub type NEi32 = NativeEndian<i32>;
+pub type NEi16 = NativeEndian<i16>;
+pub type NEi8 = NativeEndian<i8>;
+pub type NEi56 = NativeEndian<I56>;
+pub type NEi48 = NativeEndian<I48>;
+pub type NEi40 = NativeEndian<I40>;
+pub type NEi24 = NativeEndian<I24>;
+pub type NEf64 = NativeEndian<f64>;
+pub type NEf32 = NativeEndian<f32>;
+#[cfg(feature = "half")]
+pub type NEf16 = NativeEndian<f16>;
+#[cfg(feature = "half")]
+pub type NEbf16 = NativeEndian<bf16>;
+pub type NEbool64 = NativeEndian<Bool<u64>>;
+pub type NEbool32 = NativeEndian<Bool<u32>>;
+pub type NEbool16 = NativeEndian<Bool<u16>>;
+pub type NEbool8 = NativeEndian<Bool<u8>>;
+pub type NEchar32 = NativeEndian<Char32>;
+pub type NEbcd64 = NativeEndian<Bcd<u64>>;
+pub type NEbcd32 = NativeEndian<Bcd<u32>>;
+pub type NEbcd16 = NativeEndian<Bcd<u16>>;
+pub type NEbcd8 = NativeEndian<Bcd<u8>>;
+pub type NEtime64 = NativeEndian<UnixTime<i64>>;
+pub type NEtime32 = NativeEndian<UnixTime<u32>>;
+pub type NEq16_16 = NativeEndian<Fixed<i32, 16>>;
+pub type NEq8_24 = NativeEndian<Fixed<i32, 24>>;
+pub type NEq1_31 = NativeEndian<Fixed<i32, 31>>;
+pub type NEq2_14 = NativeEndian<Fixed<i16, 14>>;
+pub type NEq1_15 = NativeEndian<Fixed<i16, 15>>;
+
+// PDP-endian type aliases
+pub type PDPu32 = PdpEndian<u32>;
+pub type PDPi32 = PdpEndian<i32>;
+
+// Modbus word order type aliases
+pub type BADCu64 = PdpEndian<u64>;
+pub type BADCu32 = PdpEndian<u32>;
+pub type BADCi64 = PdpEndian<i64>;
+pub type BADCi32 = PdpEndian<i32>;
+pub type BADCf64 = PdpEndian<f64>;
+pub type BADCf32 = PdpEndian<f32>;
+pub type CDABu64 = WordSwapped<u64>;
+pub type CDABu32 = WordSwapped<u32>;
+pub type CDABi64 = WordSwapped<i64>;
+pub type CDABi32 = WordSwapped<i32>;
+pub type CDABf64 = WordSwapped<f64>;
+pub type CDABf32 = WordSwapped<f32>;
+
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "alloc")]
+pub use buf::EndianBuf;
+
+mod bits;
+pub use bits::{BitReader, BitWriter};
+
+mod bulk;
+pub use bulk::swap_slice_in_place;
+
+mod detect;
+pub use detect::DynamicReader;
+
+mod field;
+pub use field::Field;
+
+mod fixed_str;
+pub use fixed_str::FixedStr;
+
+mod frame;
+pub use frame::{FrameParser, Framing, LengthPrefix};
+
+mod header;
+pub use header::{split_header, Header};
+
+#[cfg(feature = "std")]
+mod io;
+#[cfg(all(feature = "std", any(unix, windows)))]
+pub use io::FileReadEndian;
+#[cfg(feature = "std")]
+pub use io::{ReadEndian, WriteEndian};
+
+mod mac;
+pub use mac::MacAddr;
+
+mod magic;
+pub use magic::Magic;
+
+mod reader;
+pub use reader::{ByteSource, Chain, EndianReader, Segments};
+
+mod slice;
+pub use slice::{EndianBytesExt, EndianIter, EndianSlice, EndianSliceMut};
+
+mod unaligned;
+pub use unaligned::Unaligned;
+
+mod utf16;
+pub use utf16::WideStr;
+
+mod tlv;
+pub use tlv::Tlv;
+
+mod uuid;
+pub use uuid::{Guid, Uuid};
+
+#[cfg(feature = "alloc")]
+mod vec;
+
+mod varint;
+pub use varint::{
+    decode_sleb128, decode_uleb128, decode_vlq, encode_sleb128, encode_uleb128, encode_vlq, ZigZag,
+    MAX_VARINT_LEN,
+};
+
+mod view;
+pub use view::{EndianView, EndianViewMut};
+
+mod volatile;
+pub use volatile::Volatile;
+
+mod writer;
+pub use writer::EndianWriter;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 #[cfg(test)]
 mod test;
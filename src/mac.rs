@@ -0,0 +1,110 @@
+use crate::Error;
+use core::convert::TryFrom;
+use core::fmt::{Debug, Display};
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A 6-byte MAC address (EUI-48), stored in transmission order
+pub struct MacAddr([u8; 6]);
+
+impl MacAddr {
+    /// The broadcast address `ff:ff:ff:ff:ff:ff`
+    pub const BROADCAST: Self = Self([0xff; 6]);
+
+    /// Creates an address from its bytes in transmission order
+    #[inline(always)]
+    pub const fn new(bytes: [u8; 6]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the bytes of the address in transmission order
+    pub const fn octets(self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Creates an address from the low 48 bits of an integer, returning `None` if any higher bit is set
+    pub const fn from_u64(value: u64) -> Option<Self> {
+        if value >> 48 != 0 {
+            return None;
+        }
+        let b = value.to_be_bytes();
+        Some(Self([b[2], b[3], b[4], b[5], b[6], b[7]]))
+    }
+
+    /// Returns the address as an integer, with the first transmitted byte most significant
+    pub const fn to_u64(self) -> u64 {
+        let b = self.0;
+        u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+
+    /// Returns `true` for the broadcast address
+    pub const fn is_broadcast(self) -> bool {
+        self.to_u64() == Self::BROADCAST.to_u64()
+    }
+
+    /// Returns `true` if the group bit is set (including the broadcast address)
+    pub const fn is_multicast(self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Returns `true` if the group bit is clear
+    pub const fn is_unicast(self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns `true` if the address is locally administered
+    pub const fn is_local(self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    #[inline(always)]
+    fn from(other: [u8; 6]) -> Self {
+        Self(other)
+    }
+}
+
+impl From<MacAddr> for [u8; 6] {
+    #[inline(always)]
+    fn from(other: MacAddr) -> Self {
+        other.0
+    }
+}
+
+impl From<MacAddr> for u64 {
+    #[inline(always)]
+    fn from(other: MacAddr) -> Self {
+        other.to_u64()
+    }
+}
+
+impl TryFrom<u64> for MacAddr {
+    type Error = Error;
+    fn try_from(other: u64) -> Result<Self, Error> {
+        Self::from_u64(other).ok_or(Error::out_of_range((1 << 48) - 1, other))
+    }
+}
+
+impl AsRef<[u8]> for MacAddr {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for MacAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let b = self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            b[0], b[1], b[2], b[3], b[4], b[5]
+        )
+    }
+}
+
+impl Debug for MacAddr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,56 @@
+use core::fmt::{Debug, Display};
+
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+/// A magic number stored as raw bytes, comparable against byte string literals (e.g. `b"\x7fELF"`)
+pub struct Magic<const N: usize>([u8; N]);
+
+impl<const N: usize> Magic<N> {
+    /// Creates a magic number from its bytes
+    pub const fn new(bytes: &[u8; N]) -> Self {
+        Self(*bytes)
+    }
+
+    /// Returns the bytes of the magic number
+    pub const fn bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Magic<N> {
+    fn from(other: [u8; N]) -> Self {
+        Self(other)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Magic<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for Magic<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for Magic<N> {
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        self.0 == **other
+    }
+}
+
+impl<const N: usize> Display for Magic<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0
+            .iter()
+            .try_for_each(|b| Display::fmt(&b.escape_ascii(), f))
+    }
+}
+
+impl<const N: usize> Debug for Magic<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "b\"{}\"", self)
+    }
+}
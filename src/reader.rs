@@ -0,0 +1,352 @@
+use crate::{EndianValue, Error, ErrorKind};
+
+/// A source of bytes for `EndianReader`, which provides them in one or more contiguous chunks
+///
+/// This can be implemented for ring buffers, DMA buffers or queues of received packets, e.g.:
+/// ```
+/// use storage_endian::{BEu32, ByteSource, EndianReader};
+///
+/// struct Packets<'a> {
+///     packets: &'a [&'a [u8]],
+///     offset: usize,
+/// }
+///
+/// impl ByteSource for Packets<'_> {
+///     fn fill(&mut self) -> &[u8] {
+///         while let [packet, rest @ ..] = self.packets {
+///             if self.offset < packet.len() {
+///                 return &packet[self.offset..];
+///             }
+///             (self.packets, self.offset) = (rest, 0);
+///         }
+///         &[]
+///     }
+///     fn consume(&mut self, len: usize) {
+///         self.offset += len;
+///     }
+/// }
+///
+/// let packets = [&[0x12, 0x34][..], &[], &[0x56], &[0x78, 0]];
+/// let mut reader = EndianReader::from_source(Packets { packets: &packets, offset: 0 });
+/// assert_eq!(reader.read::<BEu32>()?, 0x1234_5678);
+/// assert_eq!(reader.read::<u8>()?, 0);
+/// assert!(reader.at_end());
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+pub trait ByteSource {
+    /// Returns the next available bytes without consuming them, or an empty slice at the end of the source
+    fn fill(&mut self) -> &[u8];
+    /// Consumes `len` bytes, which must be no more than the last call to `fill` returned
+    fn consume(&mut self, len: usize);
+    /// Returns the number of bytes left, if known, so that a read past the end can fail without consuming anything
+    fn remaining(&self) -> Option<usize> {
+        None
+    }
+    /// Returns a source which reads all of this source, and then `next`
+    ///
+    /// Values which straddle the two sources are reassembled by `EndianReader`, e.g. for the two halves
+    /// of a ring buffer:
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use storage_endian::{BEu32, ByteSource, EndianReader};
+    ///
+    /// let mut ring = VecDeque::with_capacity(4);
+    /// ring.extend([0, 0, 0x12, 0x34]);
+    /// ring.drain(..2);
+    /// ring.extend([0x56, 0x78]);
+    /// let (front, back) = ring.as_slices();
+    /// let mut reader = EndianReader::from_source(front.chain(back));
+    /// assert_eq!(reader.read::<BEu32>()?, 0x1234_5678);
+    /// # Ok::<(), storage_endian::Error>(())
+    /// ```
+    fn chain<B: ByteSource>(self, next: B) -> Chain<Self, B>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// A source reading all of one source and then another, created by `ByteSource::chain`
+///
+/// Chains can be nested to read any number of segments.
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Chain<A, B> {
+    /// Returns the two sources
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: ByteSource, B: ByteSource> ByteSource for Chain<A, B> {
+    fn fill(&mut self) -> &[u8] {
+        // Checking emptiness first avoids holding a borrow of `first` while filling `second`
+        if self.first.fill().is_empty() {
+            return self.second.fill();
+        }
+        self.first.fill()
+    }
+    fn consume(&mut self, len: usize) {
+        if self.first.fill().is_empty() {
+            self.second.consume(len)
+        } else {
+            self.first.consume(len)
+        }
+    }
+    fn remaining(&self) -> Option<usize> {
+        self.first
+            .remaining()?
+            .checked_add(self.second.remaining()?)
+    }
+}
+
+impl ByteSource for &[u8] {
+    fn fill(&mut self) -> &[u8] {
+        self
+    }
+    fn consume(&mut self, len: usize) {
+        *self = &self[len..];
+    }
+    fn remaining(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+#[derive(Clone, Debug)]
+/// A source reading a sequence of slices, e.g. the fragments of a packet or a vectored I/O buffer
+///
+/// Values which straddle fragments are reassembled by `EndianReader`, and the unread bytes can be taken
+/// back without copying with `into_inner`.
+/// ```
+/// use std::io::IoSlice;
+/// use storage_endian::{EndianReader, LEu32, Segments};
+///
+/// let buffers = [IoSlice::new(&[1, 2]), IoSlice::new(&[3, 4, 5]), IoSlice::new(&[6])];
+/// let mut reader = EndianReader::from_source(Segments::new(buffers.iter().map(|buf| &**buf)));
+/// assert_eq!(reader.read::<LEu32>()?, 0x0403_0201);
+/// let (current, rest) = reader.into_inner().into_inner();
+/// assert_eq!(current, [5]);
+/// assert!(rest.eq([&[6][..]]));
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+pub struct Segments<'a, I> {
+    current: &'a [u8],
+    rest: I,
+}
+
+impl<'a, I: Iterator<Item = &'a [u8]>> Segments<'a, I> {
+    /// Creates a source reading each slice from `segments` in turn
+    pub fn new(segments: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            current: &[],
+            rest: segments.into_iter(),
+        }
+    }
+
+    /// Returns the unread part of the current segment, and the segments after it
+    pub fn into_inner(self) -> (&'a [u8], I) {
+        (self.current, self.rest)
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a [u8]>> ByteSource for Segments<'a, I> {
+    fn fill(&mut self) -> &[u8] {
+        while self.current.is_empty() {
+            match self.rest.next() {
+                Some(segment) => self.current = segment,
+                None => break,
+            }
+        }
+        self.current
+    }
+    fn consume(&mut self, len: usize) {
+        self.current = &self.current[len..];
+    }
+}
+
+/// A cursor reading values sequentially from a byte slice, or any other `ByteSource`
+///
+/// ```
+/// use storage_endian::{BEu16, EndianReader, LEu32};
+///
+/// let mut reader = EndianReader::new(b"\x00\x05hello\x07\x00\x00\x00");
+/// let len = reader.read::<BEu16>()?.value();
+/// assert_eq!(reader.read_bytes(len.into())?, b"hello");
+/// assert_eq!(reader.read::<LEu32>()?, 7);
+/// assert!(reader.is_empty());
+///
+/// let err = reader.read::<u8>().unwrap_err();
+/// assert_eq!((err.offset(), err.expected(), err.actual()), (Some(11), Some(1), Some(0)));
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct EndianReader<S> {
+    source: S,
+    position: usize,
+}
+
+impl<S: ByteSource> EndianReader<S> {
+    /// Creates a reader starting at the beginning of `source`
+    pub const fn from_source(source: S) -> Self {
+        Self {
+            source,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes read so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a reference to the source
+    pub fn get_ref(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the source, which is left positioned after the bytes read
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    // Returns the source, for reads which need to look ahead
+    pub(crate) fn source_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+
+    // Returns the number of bytes left, if the source knows
+    pub(crate) fn remaining_hint(&self) -> Option<usize> {
+        self.source.remaining()
+    }
+
+    /// Returns `true` if the source has no bytes left
+    pub fn at_end(&mut self) -> bool {
+        self.source.fill().is_empty()
+    }
+
+    // Passes each chunk of the next `len` bytes to `f`, returning an error if the source runs out first
+    fn take(&mut self, len: usize, mut f: impl FnMut(&[u8])) -> Result<(), Error> {
+        if let Some(remaining) = self.remaining_hint().filter(|&remaining| remaining < len) {
+            return Err(Error::truncated(self.position, len, remaining));
+        }
+        let available = self.source.fill();
+        if available.len() >= len {
+            f(&available[..len]);
+            self.source.consume(len);
+            self.position += len;
+            return Ok(());
+        }
+        let mut taken = 0;
+        while taken < len {
+            let chunk = self.source.fill();
+            if chunk.is_empty() {
+                return Err(Error::truncated(self.position - taken, len, taken));
+            }
+            let chunk = &chunk[..chunk.len().min(len - taken)];
+            f(chunk);
+            let chunk_len = chunk.len();
+            self.source.consume(chunk_len);
+            self.position += chunk_len;
+            taken += chunk_len;
+        }
+        Ok(())
+    }
+
+    /// Reads exactly `buf.len()` bytes into `buf`
+    ///
+    /// On error nothing is consumed if the source knows how many bytes it has left (as a slice does),
+    /// otherwise the bytes that were left are consumed.
+    pub fn read_into(&mut self, buf: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        self.take(buf.len(), |chunk| {
+            buf[filled..filled + chunk.len()].copy_from_slice(chunk);
+            filled += chunk.len();
+        })
+    }
+
+    /// Reads a value from its storage order bytes, e.g. `reader.read::<BEu32>()`
+    ///
+    /// On error nothing is consumed, as for `read_into`.
+    pub fn read<V: EndianValue>(&mut self) -> Result<V, Error> {
+        let mut bytes = V::Bytes::default();
+        self.read_into(bytes.as_mut())?;
+        Ok(V::from_storage(bytes))
+    }
+
+    /// Reads a magic number or signature, returning a `BadMagic` error if it doesn't match `magic`
+    ///
+    /// The error has the offset of the magic, and for magics up to 8 bytes long the expected and
+    /// actual bytes as big-endian integers. On error the bytes read are consumed.
+    pub fn expect_magic(&mut self, magic: &[u8]) -> Result<(), Error> {
+        let offset = self.position;
+        let (mut actual, mut matches, mut index) = (0, true, 0);
+        self.take(magic.len(), |chunk| {
+            for &byte in chunk {
+                matches &= byte == magic[index];
+                actual = actual << 8 | byte as u64;
+                index += 1;
+            }
+        })?;
+        if matches {
+            return Ok(());
+        }
+        let error = Error::new(ErrorKind::BadMagic).at(offset);
+        if magic.len() > 8 {
+            return Err(error);
+        }
+        let expected = magic
+            .iter()
+            .fold(0, |value, &byte| value << 8 | byte as u64);
+        Err(error.values(expected, actual))
+    }
+
+    /// Skips the next `len` bytes
+    ///
+    /// On error nothing is consumed, as for `read_into`.
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.take(len, |_| ())
+    }
+}
+
+impl<'a> EndianReader<&'a [u8]> {
+    /// Creates a reader starting at the beginning of `bytes`
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self::from_source(bytes)
+    }
+
+    /// Returns the number of bytes left to read
+    pub fn remaining(&self) -> usize {
+        self.source.len()
+    }
+
+    /// Returns `true` if there are no bytes left to read
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    /// Returns the bytes left to read, without consuming them
+    pub fn remaining_bytes(&self) -> &'a [u8] {
+        self.source
+    }
+
+    /// Reads the next `len` bytes, without copying them
+    ///
+    /// On error nothing is consumed.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes =
+            self.source
+                .get(..len)
+                .ok_or(Error::truncated(self.position, len, self.remaining()))?;
+        self.source = &self.source[len..];
+        self.position += len;
+        Ok(bytes)
+    }
+}
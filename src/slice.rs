@@ -0,0 +1,241 @@
+use crate::{Endianness, Primitive, BE, LE};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+
+// Reads the value stored in `chunk`, which is exactly one value long
+fn read<T: Primitive, E: Endianness>(chunk: &[u8]) -> T {
+    let mut bytes = T::Bytes::default();
+    bytes.as_mut().copy_from_slice(chunk);
+    E::from_bytes(bytes)
+}
+
+/// A view of a byte buffer as values stored with byte order `E`, with no alignment requirement
+///
+/// Trailing bytes which don't make up a whole value are ignored.
+pub struct EndianSlice<'a, T, E> {
+    bytes: &'a [u8],
+    _marker: PhantomData<(T, E)>,
+}
+
+impl<'a, T: Primitive, E: Endianness> EndianSlice<'a, T, E> {
+    /// Creates a view of `bytes`
+    pub fn new(bytes: &'a [u8]) -> Self {
+        let len = bytes.len() - bytes.len() % core::mem::size_of::<T>();
+        Self {
+            bytes: &bytes[..len],
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.bytes.len() / core::mem::size_of::<T>()
+    }
+
+    /// Returns `true` if there are no values
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the native value at `index`, or `None` if out of bounds
+    pub fn get(&self, index: usize) -> Option<T> {
+        let size = core::mem::size_of::<T>();
+        let offset = index.checked_mul(size)?;
+        Some(read::<T, E>(self.bytes.get(offset..)?.get(..size)?))
+    }
+
+    /// Returns an iterator over the native values
+    pub fn iter(&self) -> EndianIter<'a, T, E> {
+        EndianIter::new(self.bytes)
+    }
+
+    /// Returns the underlying bytes, excluding any trailing partial value
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<T, E> Clone for EndianSlice<'_, T, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E> Copy for EndianSlice<'_, T, E> {}
+
+impl<'a, T: Primitive, E: Endianness> IntoIterator for EndianSlice<'a, T, E> {
+    type Item = T;
+    type IntoIter = EndianIter<'a, T, E>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Primitive, E: Endianness> Debug for EndianSlice<'_, T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A mutable view of a byte buffer as values stored with byte order `E`, with no alignment requirement
+///
+/// Trailing bytes which don't make up a whole value are ignored.
+pub struct EndianSliceMut<'a, T, E> {
+    bytes: &'a mut [u8],
+    _marker: PhantomData<(T, E)>,
+}
+
+impl<'a, T: Primitive, E: Endianness> EndianSliceMut<'a, T, E> {
+    /// Creates a mutable view of `bytes`
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        let len = bytes.len() - bytes.len() % core::mem::size_of::<T>();
+        Self {
+            bytes: &mut bytes[..len],
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a shared view of the same values
+    pub fn as_slice(&self) -> EndianSlice<'_, T, E> {
+        EndianSlice::new(self.bytes)
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if there are no values
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the native value at `index`, or `None` if out of bounds
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.as_slice().get(index)
+    }
+
+    /// Stores a native value at `index`, returning `None` if out of bounds
+    pub fn set(&mut self, index: usize, value: T) -> Option<()> {
+        let size = core::mem::size_of::<T>();
+        let offset = index.checked_mul(size)?;
+        self.bytes
+            .get_mut(offset..)?
+            .get_mut(..size)?
+            .copy_from_slice(E::to_bytes(value).as_ref());
+        Some(())
+    }
+
+    /// Returns an iterator over the native values
+    pub fn iter(&self) -> EndianIter<'_, T, E> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the underlying bytes, excluding any trailing partial value
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+}
+
+impl<T: Primitive, E: Endianness> Debug for EndianSliceMut<'_, T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Debug::fmt(&self.as_slice(), f)
+    }
+}
+
+/// Iterator over the native values stored in a byte slice
+pub struct EndianIter<'a, T, E> {
+    chunks: core::slice::ChunksExact<'a, u8>,
+    _marker: PhantomData<(T, E)>,
+}
+
+impl<'a, T: Primitive, E: Endianness> EndianIter<'a, T, E> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            chunks: bytes.chunks_exact(core::mem::size_of::<T>()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the trailing bytes which don't make up a whole value
+    pub fn remainder(&self) -> &'a [u8] {
+        self.chunks.remainder()
+    }
+}
+
+impl<T: Primitive, E: Endianness> Iterator for EndianIter<'_, T, E> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        self.chunks.next().map(read::<T, E>)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.chunks.nth(n).map(read::<T, E>)
+    }
+}
+
+impl<T: Primitive, E: Endianness> DoubleEndedIterator for EndianIter<'_, T, E> {
+    fn next_back(&mut self) -> Option<T> {
+        self.chunks.next_back().map(read::<T, E>)
+    }
+}
+
+impl<T: Primitive, E: Endianness> ExactSizeIterator for EndianIter<'_, T, E> {}
+
+impl<T, E> Clone for EndianIter<'_, T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+macro_rules! iter_methods {
+    ( $( $be:ident / $le:ident => $t:ident ),* ) => { $(
+        /// Returns an iterator over the big-endian values in the slice
+        fn $be(&self) -> EndianIter<'_, $t, BE> {
+            self.endian_iter()
+        }
+        /// Returns an iterator over the little-endian values in the slice
+        fn $le(&self) -> EndianIter<'_, $t, LE> {
+            self.endian_iter()
+        }
+    )* };
+}
+
+/// Extension methods decoding the values stored in a byte slice
+///
+/// ```
+/// use storage_endian::EndianBytesExt;
+///
+/// let table = [0, 0, 0, 1, 0, 0, 0, 2, 0xff];
+/// let mut entries = table.be_u32_iter();
+/// assert!(entries.by_ref().eq([1, 2]));
+/// assert_eq!(entries.remainder(), [0xff]);
+/// ```
+pub trait EndianBytesExt {
+    /// Returns an iterator over the values stored in the slice with byte order `E`
+    fn endian_iter<T: Primitive, E: Endianness>(&self) -> EndianIter<'_, T, E>;
+
+    iter_methods!(
+        be_u16_iter / le_u16_iter => u16,
+        be_u32_iter / le_u32_iter => u32,
+        be_u64_iter / le_u64_iter => u64,
+        be_u128_iter / le_u128_iter => u128,
+        be_i16_iter / le_i16_iter => i16,
+        be_i32_iter / le_i32_iter => i32,
+        be_i64_iter / le_i64_iter => i64,
+        be_i128_iter / le_i128_iter => i128,
+        be_f32_iter / le_f32_iter => f32,
+        be_f64_iter / le_f64_iter => f64
+    );
+}
+
+impl EndianBytesExt for [u8] {
+    fn endian_iter<T: Primitive, E: Endianness>(&self) -> EndianIter<'_, T, E> {
+        EndianIter::new(self)
+    }
+}
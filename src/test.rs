@@ -172,6 +172,2264 @@ fn little_endian_format() {
     assert_eq!(format!("{:b}", value), LE_U8_BIN);
 }
 
+#[test]
+fn endian_conversion() {
+    let be: BEu32 = unsafe { transmute(DATA_32) };
+    let le: LEu32 = be.into();
+    assert_eq!(le, BE_U32);
+    assert_eq!(
+        unsafe { transmute::<LEu32, [u8; 4]>(le) },
+        [0xa3, 0xa2, 0xa1, 0xa0]
+    );
+    let be: BEu32 = le.into();
+    assert_eq!(unsafe { transmute::<BEu32, [u8; 4]>(be) }, DATA_32);
+
+    let le: LEi64 = unsafe { transmute(DATA_64) };
+    let be = BEi64::from(le);
+    assert_eq!(be, LE_U64 as i64);
+    assert_eq!(LEi64::from(be), LE_U64 as i64);
+}
+
+#[test]
+fn endian_comparison() {
+    let be: BEu32 = unsafe { transmute(DATA_32) };
+    let le: LEu32 = unsafe { transmute(DATA_32) };
+    assert!(be != le);
+    assert!(be < le);
+    assert!(le > be);
+    assert_eq!(be, LEu32::from(BE_U32));
+    assert_eq!(LEu32::from(BE_U32), be);
+
+    let be: BEi16 = unsafe { transmute(DATA_16) };
+    let le: LEi16 = unsafe { transmute(DATA_16) };
+    assert!(be < le);
+    assert!(be >= LEi16::from(BE_U16 as i16));
+}
+
+#[test]
+fn widening_equality() {
+    let value: BEu16 = unsafe { transmute(DATA_16) };
+    assert!(value.eq_widened(BE_U16 as u32));
+    assert!(value.eq_widened(BE_U16 as u64));
+    assert!(value.eq_widened(BE_U16 as i32));
+    assert!(!value.eq_widened(0x1_a0a1u32));
+    assert!(value == 0xa0a1);
+
+    let value: LEi8 = unsafe { transmute(DATA_8) };
+    assert!(value.eq_widened(-96i64));
+    assert!(!value.eq_widened(0xa0i32));
+
+    let value: BEu32 = unsafe { transmute(DATA_32) };
+    assert!(value.eq_widened(BE_U32 as u64));
+    assert!(!value.eq_widened(BE_U32 as u64 | 1 << 32));
+    let value: LEu64 = unsafe { transmute(DATA_64) };
+    assert!(value.eq_widened(LE_U64 as u128));
+    assert!(value.eq_widened(LE_U64 as i128));
+
+    let value = BEi32::from(-1);
+    assert_eq!(value, -1i64);
+    assert!(value != 0xffff_ffffi64);
+    assert!(value == -1);
+}
+
+#[test]
+fn min_max_clamp() {
+    let value: BEu32 = unsafe { transmute(DATA_32) };
+    assert_eq!(value.min(0x1000u32), 0x1000u32);
+    assert_eq!(value.max(0x1000u32), BE_U32);
+    assert_eq!(value.min(BEu32::from(0x10u32)), 0x10u32);
+    assert_eq!(value.max(LEu32::from(u32::MAX)), u32::MAX);
+    assert_eq!(value.clamp(0u32, 0xffu32), 0xffu32);
+    assert_eq!(value.clamp(0u32, u32::MAX), BE_U32);
+
+    let value: LEi16 = unsafe { transmute(DATA_16) };
+    assert_eq!(value.clamp(-100i16, 100i16), -100i16);
+    assert_eq!(Ord::min(value, LEi16::from(0i16)), LE_U16 as i16);
+}
+
+#[test]
+fn bignum_helpers() {
+    let a = [BEu64::from(u64::MAX), BEu64::from(1u64)];
+    let b = [BEu64::from(1u64), BEu64::from(2u64)];
+    let (low, carry) = a[0].carrying_add(b[0], false);
+    let (high, carry) = a[1].carrying_add(b[1], carry);
+    assert_eq!([low, high], [0u64, 4]);
+    assert!(!carry);
+
+    let (low, borrow) = b[0].borrowing_sub(a[0], false);
+    let (high, borrow) = b[1].borrowing_sub(a[1], borrow);
+    assert_eq!([low, high], [2u64, 0]);
+    assert!(!borrow);
+
+    let (low, high) = LEu32::from(u32::MAX).widening_mul(LEu32::from(2u32));
+    assert_eq!(low, 0xffff_fffeu32);
+    assert_eq!(high, 1u32);
+    let (low, high) = BEu128::from(BE_U128).widening_mul(1u128);
+    assert_eq!(low, BE_U128);
+    assert_eq!(high, 0u128);
+    let (low, high) = BEu128::from(u128::MAX).widening_mul(u128::MAX);
+    assert_eq!(low, 1u128);
+    assert_eq!(high, u128::MAX - 1);
+    assert_eq!(
+        LEu8::from(u8::MAX).carrying_add(0u8, true),
+        (LEu8::from(0u8), true)
+    );
+    assert_eq!(
+        LEu8::from(0u8).borrowing_sub(0u8, true),
+        (LEu8::from(u8::MAX), true)
+    );
+}
+
+#[test]
+fn rounding_helpers() {
+    let value = BEu32::from(1000u32);
+    assert_eq!(value.midpoint(LEu32::from(u32::MAX)), 0x8000_01f3u32);
+    assert_eq!(value.isqrt(), 31u32);
+    assert_eq!(value.next_multiple_of(512u32), 1024u32);
+    assert_eq!(
+        value.checked_next_multiple_of(BEu32::from(8u32)),
+        Some(BEu32::from(1000u32))
+    );
+    assert_eq!(value.checked_next_multiple_of(0u32), None);
+    assert_eq!(BEu32::from(u32::MAX).checked_next_multiple_of(2u32), None);
+
+    let value = LEi16::from(-100i16);
+    assert_eq!(value.midpoint(50i16), -25i16);
+    assert_eq!(LEi16::from(100i16).isqrt(), 10i16);
+    assert_eq!(LEi16::from(-3i16).midpoint(0i16), -1i16);
+    assert_eq!(LEi16::from(3i16).midpoint(0i16), 1i16);
+    assert_eq!(BEu64::from(u64::MAX).isqrt(), u32::MAX as u64);
+    assert_eq!(BEi32::from(i32::MAX).isqrt(), 46340i32);
+}
+
+#[test]
+fn casts() {
+    let value: BEu32 = unsafe { transmute(DATA_32) };
+    assert_eq!(value.as_u8(), 0xa3);
+    assert_eq!(value.as_i8(), -93);
+    assert_eq!(value.as_u16(), 0xa2a3);
+    assert_eq!(value.as_u64(), BE_U32 as u64);
+    assert_eq!(value.as_i64(), BE_U32 as i64);
+
+    let value: LEi16 = unsafe { transmute(DATA_16) };
+    assert_eq!(value.as_i32(), LE_U16 as i16 as i32);
+    assert_eq!(value.as_u32(), 0xffff_a1a0);
+    assert_eq!(value.as_u128(), u128::MAX - 0x5e5f);
+    assert_eq!(value.as_usize(), LE_U16 as i16 as usize);
+}
+
+#[test]
+fn from_bytes() {
+    const BE: BEu128 = BEu128::from_bytes(DATA_128);
+    const LE: LEu128 = LEu128::from_bytes(DATA_128);
+    assert_eq!(BE, BE_U128);
+    assert_eq!(LE, LE_U128);
+    assert_eq!(BEu64::from_bytes(DATA_64), BE_U64);
+    assert_eq!(LEu64::from_bytes(DATA_64), LE_U64);
+    assert_eq!(BEu32::from_bytes(DATA_32), BE_U32);
+    assert_eq!(LEu32::from_bytes(DATA_32), LE_U32);
+    assert_eq!(BEi16::from_bytes(DATA_16), BE_U16 as i16);
+    assert_eq!(LEi16::from_bytes(DATA_16), LE_U16 as i16);
+    assert_eq!(BEi8::from_bytes(DATA_8), BE_U8 as i8);
+    assert_eq!(LEu8::from_bytes(DATA_8), LE_U8);
+}
+
+#[test]
+fn to_bytes() {
+    assert_eq!(BEu128::from(BE_U128).to_bytes(), DATA_128);
+    assert_eq!(LEu128::from(LE_U128).to_bytes(), DATA_128);
+    assert_eq!(BEu64::from(BE_U64).to_bytes(), DATA_64);
+    assert_eq!(LEu64::from(LE_U64).to_bytes(), DATA_64);
+    assert_eq!(BEi32::from(BE_U32 as i32).to_bytes(), DATA_32);
+    assert_eq!(LEi32::from(LE_U32 as i32).to_bytes(), DATA_32);
+    assert_eq!(BEu16::from(BE_U16).to_bytes(), DATA_16);
+    assert_eq!(LEu16::from(LE_U16).to_bytes(), DATA_16);
+    assert_eq!(BEu8::from(BE_U8).to_bytes(), DATA_8);
+    assert_eq!(LEi8::from(LE_U8 as i8).to_bytes(), DATA_8);
+}
+
+#[test]
+fn as_bytes() {
+    let mut value = BEu32::from(BE_U32);
+    assert_eq!(value.as_bytes(), &DATA_32);
+    value.as_bytes_mut()[3] = 0xff;
+    assert_eq!(value, 0xa0a1_a2ffu32);
+
+    let mut value = LEu64::from(LE_U64);
+    assert_eq!(value.as_bytes(), &DATA_64);
+    value.as_bytes_mut().copy_from_slice(&DATA_64[..]);
+    assert_eq!(value, LE_U64);
+    value.as_bytes_mut()[0] = 0;
+    assert_eq!(value, LE_U64 & !0xff);
+}
+
+#[test]
+fn as_ref() {
+    fn checksum(data: impl AsRef<[u8]>) -> u8 {
+        data.as_ref().iter().fold(0, |sum, b| sum.wrapping_add(*b))
+    }
+    assert_eq!(BEu32::from(BE_U32).as_ref(), &DATA_32[..]);
+    assert_eq!(LEi128::from(LE_U128 as i128).as_ref(), &DATA_128[..]);
+    assert_eq!(checksum(BEu16::from(BE_U16)), 0x41);
+    assert_eq!(checksum(LEu16::from(BE_U16)), 0x41);
+}
+
+#[test]
+fn bytes() {
+    assert!(BEu64::from(BE_U64).bytes().eq(DATA_64));
+    assert!(LEu64::from(LE_U64).bytes().eq(DATA_64));
+    assert!(LEi16::from(LE_U16 as i16).bytes().rev().eq([0xa1, 0xa0]));
+
+    let mut out = [0u8; 4];
+    for (i, b) in BEu32::from(BE_U32).into_iter().enumerate() {
+        out[i] = b;
+    }
+    assert_eq!(out, DATA_32);
+}
+
+#[test]
+fn read_write_slice() {
+    assert_eq!(
+        BEu32::read_from(&DATA_128, 0),
+        Some(BEu32::from(0xa0a1_a2a3u32))
+    );
+    assert_eq!(
+        LEu32::read_from(&DATA_128, 12),
+        Some(LEu32::from(0xafae_adacu32))
+    );
+    assert_eq!(BEu16::read_from(&DATA_128, 15), None);
+    assert_eq!(BEu16::read_from(&DATA_128, 17), None);
+    assert_eq!(BEu16::read_from(&DATA_128, usize::MAX), None);
+
+    let mut buf = [0u8; 6];
+    assert_eq!(BEu32::from(BE_U32).write_into(&mut buf, 1), Some(()));
+    assert_eq!(buf, [0x00, 0xa0, 0xa1, 0xa2, 0xa3, 0x00]);
+    assert_eq!(LEu16::from(LE_U16).write_into(&mut buf, 4), Some(()));
+    assert_eq!(buf, [0x00, 0xa0, 0xa1, 0xa2, 0xa0, 0xa1]);
+    assert_eq!(LEu16::from(LE_U16).write_into(&mut buf, 5), None);
+    assert_eq!(buf, [0x00, 0xa0, 0xa1, 0xa2, 0xa0, 0xa1]);
+}
+
+#[test]
+fn try_from_slice() {
+    use core::convert::TryFrom;
+
+    assert_eq!(BEu64::try_from(&DATA_64[..]), Ok(BEu64::from(BE_U64)));
+    assert_eq!(LEu64::try_from(&DATA_64[..]), Ok(LEu64::from(LE_U64)));
+    assert_eq!(BEu64::try_from(&DATA_128[..]), Err(Error::length(8, 16)));
+    assert_eq!(
+        BEu64::try_from(&DATA_128[..]).unwrap_err().kind(),
+        ErrorKind::TooLong
+    );
+    let err = LEi16::try_from(&DATA_8[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Truncated);
+    assert_eq!((err.expected(), err.actual()), (Some(2), Some(1)));
+    assert_eq!(format!("{}", err), "data truncated (expected 2, got 1)");
+}
+
+#[cfg(feature = "raw-access")]
+#[test]
+fn raw_access() {
+    let value = BEu32::from_bytes(DATA_32);
+    assert_eq!(value.to_raw(), u32::from_ne_bytes(DATA_32));
+    assert_eq!(BEu32::from_raw(value.to_raw()), value);
+    assert_eq!(LEu16::from_raw(u16::from_ne_bytes(DATA_16)), LE_U16);
+}
+
+#[test]
+fn sortable_bytes() {
+    assert_eq!(
+        LEu32::from(LE_U32).to_sortable_bytes(),
+        [0xa3, 0xa2, 0xa1, 0xa0]
+    );
+    assert_eq!(BEu32::from(BE_U32).to_sortable_bytes(), DATA_32);
+    assert_eq!(BEi16::from(-1i16).to_sortable_bytes(), [0x7f, 0xff]);
+    assert_eq!(BEi16::from(0i16).to_sortable_bytes(), [0x80, 0x00]);
+
+    let values = [i64::MIN, -300, -1, 0, 1, 255, 256, i64::MAX];
+    for pair in values.windows(2) {
+        let a = LEi64::from(pair[0]).to_sortable_bytes();
+        let b = LEi64::from(pair[1]).to_sortable_bytes();
+        assert!(a < b);
+        assert_eq!(LEi64::from_sortable_bytes(a), pair[0]);
+        assert_eq!(BEi64::from_sortable_bytes(b), pair[1]);
+    }
+}
+
+#[test]
+fn get_set_update() {
+    let mut value = BEu32::from_bytes(DATA_32);
+    assert_eq!(value.get(), BE_U32);
+    value.set(LE_U32);
+    assert_eq!(value.to_bytes(), [0xa3, 0xa2, 0xa1, 0xa0]);
+    value.update(|v| v + 1);
+    assert_eq!(value.get(), LE_U32 + 1);
+
+    let mut value = LEi8::from(-1i8);
+    value.update(i8::wrapping_neg);
+    assert_eq!(value.get(), 1);
+}
+
+#[test]
+fn replace_take() {
+    let mut value = LEu64::from_bytes(DATA_64);
+    assert_eq!(value.replace(BE_U64), LE_U64);
+    assert_eq!(
+        value.to_bytes(),
+        [0xa7, 0xa6, 0xa5, 0xa4, 0xa3, 0xa2, 0xa1, 0xa0]
+    );
+    assert_eq!(value.take(), BE_U64);
+    assert_eq!(value.to_bytes(), [0; 8]);
+    assert_eq!(value.take(), 0);
+}
+
+#[test]
+fn const_new_value() {
+    const MAGIC: BEu32 = BEu32::new(0x1337_beef);
+    const TABLE: [LEi16; 2] = [LEi16::new(-2), LEi16::new(0x1234)];
+    const VALUE: u32 = MAGIC.value();
+    assert_eq!(MAGIC.to_bytes(), [0x13, 0x37, 0xbe, 0xef]);
+    assert_eq!(VALUE, 0x1337_beef);
+    assert_eq!(TABLE[0].to_bytes(), [0xfe, 0xff]);
+    assert_eq!(TABLE[1].value(), 0x1234);
+    assert_eq!(BEu128::new(BE_U128).to_bytes(), DATA_128);
+}
+
+#[test]
+fn native_endian() {
+    let value = NEu32::from_bytes(DATA_32);
+    assert_eq!(value, u32::from_ne_bytes(DATA_32));
+    assert_eq!(NEu32::new(BE_U32).to_bytes(), BE_U32.to_ne_bytes());
+    assert_eq!(format!("{:x}", NEu16::new(0xa0a1)), "a0a1");
+    assert_eq!(BEu32::from(value), value);
+    assert_eq!(LEu32::from(value), value);
+    assert_eq!(NEu32::from(BEu32::from_bytes(DATA_32)), BE_U32);
+    assert!(NEu32::from(LE_U32) > BEu32::from_bytes(DATA_32));
+    assert_eq!(NEi64::from(-5i64) + 10, 5i64);
+}
+
+#[test]
+fn floats() {
+    let value = BEf32::from_bytes([0x40, 0x49, 0x0f, 0xdb]);
+    assert_eq!(value, core::f32::consts::PI);
+    assert_eq!(LEf32::from(value).to_bytes(), [0xdb, 0x0f, 0x49, 0x40]);
+    assert_eq!(format!("{:.2}", value), "3.14");
+    assert_eq!(format!("{:e}", BEf64::new(1500.0)), "1.5e3");
+    assert!(value > 3.0);
+    assert!(value < LEf32::new(4.0));
+    assert_eq!((value * 2.0).to_bytes(), [0x40, 0xc9, 0x0f, 0xdb]);
+
+    let value = LEf64::new(-0.5);
+    assert_eq!(value.to_bytes(), [0, 0, 0, 0, 0, 0, 0xe0, 0xbf]);
+    assert_eq!(
+        BEf64::read_from(&[0xbf, 0xe0, 0, 0, 0, 0, 0, 0], 0),
+        Some(BEf64::from(value))
+    );
+    assert!(BEf64::new(f64::NAN) != BEf64::new(f64::NAN));
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn half_floats() {
+    use half::{bf16, f16};
+
+    let value = BEf16::new(f16::from_f32(1.5));
+    assert_eq!(value.to_bytes(), [0x3e, 0x00]);
+    assert_eq!(LEf16::from(value).to_bytes(), [0x00, 0x3e]);
+    assert_eq!(f16::from(value), f16::from_f32(1.5));
+    assert_eq!(format!("{}", value), "1.5");
+    assert!(value > f16::ONE);
+    assert_eq!((value + f16::ONE).value(), f16::from_f32(2.5));
+    assert!(BEf16::new(f16::NAN) != BEf16::new(f16::NAN));
+
+    let value = LEbf16::from_bytes([0x80, 0xbf]);
+    assert_eq!(value, bf16::from_f32(-1.0));
+    assert_eq!(BEbf16::from(value).to_bytes(), [0xbf, 0x80]);
+    let mut reader = EndianReader::new(&[0x3c, 0x00]);
+    assert_eq!(reader.read::<BEf16>(), Ok(BEf16::new(f16::ONE)));
+}
+
+#[test]
+fn odd_width() {
+    use core::convert::TryFrom;
+
+    let value = BEu24::from_bytes([0xa0, 0xa1, 0xa2]);
+    assert_eq!(value, 0xa0a1a2);
+    assert_eq!(u32::from(value), 0xa0a1a2);
+    assert_eq!(format!("{:x} {:?}", value, value), "a0a1a2 10527138");
+    assert_eq!(LEu24::from_bytes([0xa0, 0xa1, 0xa2]), 0xa2a1a0);
+    assert_eq!(LEu24::new(0x123456).to_bytes(), [0x56, 0x34, 0x12]);
+    assert_eq!(NEu24::new(0x123456).value(), 0x123456);
+    assert_eq!(
+        BEu24::try_from(0x0100_0000),
+        Err(Error::new(ErrorKind::OutOfRange).values(0xff_ffff, 0x0100_0000))
+    );
+    assert_eq!(
+        BEu24::checked_new(0xff_ffff),
+        Some(BEu24::from_bytes([0xff; 3]))
+    );
+
+    const MAC: BEu48 = BEu48::new(0x0012_3456_789a);
+    assert_eq!(MAC.to_bytes(), [0x00, 0x12, 0x34, 0x56, 0x78, 0x9a]);
+    assert_eq!(LEu40::from_bytes([1, 2, 3, 4, 5]), 0x05_0403_0201);
+    assert_eq!(BEu56::from_bytes(*b"abcdefg").value(), 0x61_6263_6465_6667);
+    assert!(LEu56::try_from(u64::MAX).is_err());
+    assert!(BEu40::new(5) < BEu40::new(6));
+    assert!(BEu40::new(5) < 6);
+
+    let mut value = LEu48::new(0);
+    value.as_bytes_mut()[5] = 0x80;
+    assert_eq!(value, 0x8000_0000_0000);
+}
+
+#[test]
+fn odd_width_signed() {
+    use core::convert::TryFrom;
+
+    let sample = LEi24::from_bytes([0x00, 0x00, 0x80]);
+    assert_eq!(sample, -0x80_0000);
+    assert_eq!(i32::from(sample), -8388608);
+    assert_eq!(LEi24::new(-1).to_bytes(), [0xff; 3]);
+    assert_eq!(BEi24::new(-2).to_bytes(), [0xff, 0xff, 0xfe]);
+    assert_eq!(BEi24::from_bytes([0x7f, 0xff, 0xff]), 0x7f_ffff);
+    assert_eq!(
+        BEi24::try_from(0x80_0000),
+        Err(Error::new(ErrorKind::OutOfRange).values(0x7f_ffff, 0x80_0000))
+    );
+    assert_eq!(
+        BEi24::try_from(-0x80_0001),
+        Err(Error::new(ErrorKind::OutOfRange))
+    );
+    assert!(BEi24::new(-1) < BEi24::new(0));
+
+    assert_eq!(BEi40::from_bytes([0x80, 0, 0, 0, 0]), -(1i64 << 39));
+    assert_eq!(LEi48::new(-300).value(), -300);
+    assert_eq!(NEi56::new(i64::from(i32::MIN)).value(), i64::from(i32::MIN));
+    assert_eq!(format!("{}", BEi48::new(-42)), "-42");
+}
+
+#[test]
+fn byte_width_generic() {
+    assert_eq!(BigEndian::<UInt<1>>::from_bytes([0xa0]), 0xa0u8);
+    assert_eq!(BigEndian::<UInt<4>>::from_bytes(DATA_32), BE_U32);
+    assert_eq!(LittleEndian::<UInt<8>>::from_bytes(DATA_64), LE_U64);
+    assert_eq!(
+        BigEndian::<UInt<9>>::new(0x01_0203_0405_0607_0809).to_bytes(),
+        [1, 2, 3, 4, 5, 6, 7, 8, 9]
+    );
+    assert_eq!(LittleEndian::<UInt<16>>::from_bytes(DATA_128), LE_U128);
+    assert_eq!(BigEndian::<Int<12>>::new(-1).to_bytes(), [0xff; 12]);
+    assert_eq!(LittleEndian::<Int<2>>::from_bytes(DATA_16), LE_U16 as i16);
+    assert_eq!(
+        BigEndian::<Int<11>>::from_bytes([0x80; 11]).value() >> 80,
+        -128
+    );
+    assert!(BigEndian::<UInt<15>>::checked_new(u128::MAX).is_none());
+    assert_eq!(core::mem::size_of::<LittleEndian<UInt<13>>>(), 13);
+    assert_eq!(core::mem::align_of::<LittleEndian<UInt<13>>>(), 1);
+}
+
+#[test]
+#[should_panic]
+fn odd_width_overflow() {
+    BEu24::new(0x0100_0000);
+}
+
+#[test]
+fn non_zero() {
+    use core::convert::TryFrom;
+    use core::num::{NonZeroI16, NonZeroU32};
+
+    assert_eq!(core::mem::size_of::<Option<BigEndian<NonZeroU32>>>(), 4);
+    assert_eq!(core::mem::size_of::<Option<LittleEndian<NonZeroI16>>>(), 2);
+
+    let value = BigEndian::<NonZeroU32>::from_bytes(DATA_32).unwrap();
+    assert_eq!(u32::from(value), BE_U32);
+    assert_eq!(format!("{:x}", value), BE_U32_LHEX);
+    assert_eq!(value.to_bytes(), DATA_32);
+    assert!(BigEndian::<NonZeroU32>::from_bytes([0; 4]).is_none());
+    assert!(BigEndian::<NonZeroU32>::checked_new(0).is_none());
+    assert_eq!(
+        LittleEndian::<NonZeroU32>::try_from(0).map_err(|e| e.kind()),
+        Err(ErrorKind::OutOfRange)
+    );
+
+    let value = LittleEndian::<NonZeroI16>::try_from(-2).unwrap();
+    assert_eq!(value.to_bytes(), [0xfe, 0xff]);
+    assert_eq!(value.value(), NonZeroI16::new(-2).unwrap());
+    assert!(value < LittleEndian::from(NonZeroI16::new(1).unwrap()));
+}
+
+#[test]
+fn booleans() {
+    assert_eq!(BEbool32::new(true).to_bytes(), [0, 0, 0, 1]);
+    assert_eq!(LEbool32::new(true).to_bytes(), [1, 0, 0, 0]);
+    assert_eq!(LEbool16::from(false).to_bytes(), [0, 0]);
+
+    let value = BEbool32::from_bytes([0, 0, 1, 0]);
+    assert_eq!(value, true);
+    assert!(bool::from(value));
+    assert_eq!(value.int_value(), 0x100);
+    assert_eq!(value.checked_value(), None);
+    assert_eq!(value, BEbool32::new(true));
+    assert_eq!(format!("{} {:?}", value, value), "true true");
+
+    let value = LEbool8::from_bytes([1]);
+    assert_eq!(value.checked_value(), Some(true));
+    // Non-canonical encodings compare and hash like the canonical one, though their bytes differ
+    let other = LEbool8::from_bytes([2]);
+    assert_eq!(value, other);
+    assert_ne!(value.to_bytes(), other.to_bytes());
+    let hash = |value: LEbool8| {
+        use core::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(value), hash(other));
+    assert_ne!(hash(value), hash(LEbool8::new(false)));
+    assert_eq!(NEbool64::from_bytes([0; 8]).checked_value(), Some(false));
+}
+
+#[test]
+fn utf32() {
+    use core::convert::TryFrom;
+
+    assert_eq!(BEchar32::new('€').to_bytes(), [0x00, 0x00, 0x20, 0xac]);
+    assert_eq!(LEchar32::from('A').to_bytes(), [0x41, 0, 0, 0]);
+
+    let value = LEchar32::from_bytes([0x00, 0xf6, 0x01, 0x00]);
+    assert_eq!(value, '😀');
+    assert_eq!(char::try_from(value), Ok('😀'));
+    assert_eq!(format!("{:?}", value), "'😀'");
+
+    let value = BEchar32::from_bytes([0x00, 0x00, 0xd8, 0x00]);
+    assert_eq!(value.int_value(), 0xd800);
+    assert_eq!(value.checked_value(), None);
+    assert!(char::try_from(value).is_err());
+    assert_eq!(format!("{:?}", value), "0xd800");
+}
+
+#[test]
+fn utf16() {
+    let units = [
+        LEu16::new(0x48),
+        LEu16::new(0x69),
+        LEu16::new(0xd83d),
+        LEu16::new(0xde00),
+        LEu16::new(0),
+    ];
+    let wide = WideStr::new(&units);
+    assert_eq!(wide.len(), 5);
+    assert!(wide != "Hi😀");
+    let wide = WideStr::until_nul(&units);
+    assert_eq!(wide.len(), 4);
+    assert_eq!(wide, "Hi😀");
+    assert!(wide.chars().eq("Hi😀".chars().map(Ok)));
+    assert_eq!(format!("{} {:?}", wide, wide), "Hi😀 \"Hi😀\"");
+
+    let units = [
+        BEu16::new(0x48),
+        BEu16::new(0x69),
+        BEu16::new(0xd83d),
+        BEu16::new(0xde00),
+    ];
+    assert_eq!(WideStr::new(&units), wide);
+    assert_eq!(
+        WideStr::new(&units[..3]).chars_lossy().last(),
+        Some('\u{fffd}')
+    );
+    assert!(WideStr::new(&units[..3]).chars().last().unwrap().is_err());
+    assert!(WideStr::<BEu16>::new(&[]).is_empty());
+}
+
+#[test]
+fn enum_repr() {
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Record {
+        Header = 0x10,
+        Data = 0x2000,
+    }
+    impl EndianRepr for Record {
+        type Repr = u32;
+        fn to_repr(self) -> u32 {
+            self as u32
+        }
+        fn from_repr(repr: u32) -> Option<Self> {
+            match repr {
+                0x10 => Some(Record::Header),
+                0x2000 => Some(Record::Data),
+                _ => None,
+            }
+        }
+    }
+
+    let value = LittleEndian::<Enum<Record>>::new(Record::Data);
+    assert_eq!(value.int_value(), 0x2000);
+    assert_eq!(value, Record::Data);
+    assert!(value != Record::Header);
+    assert_eq!(value, LittleEndian::from(Record::Data));
+    assert_eq!(format!("{:?}", value), "Data");
+    assert_eq!(
+        unsafe { transmute::<LittleEndian<Enum<Record>>, [u8; 4]>(value) },
+        [0x00, 0x20, 0x00, 0x00]
+    );
+
+    let value: BigEndian<Enum<Record>> = unsafe { transmute(DATA_32) };
+    assert_eq!(value.checked_value(), None);
+    assert_eq!(format!("{:?}", value), BE_U32_DEC);
+}
+
+#[test]
+fn dynamic_endian() {
+    #[repr(C)]
+    struct Header {
+        order: [u8; 2],
+        magic: DynamicEndian<u16>,
+        offset: DynamicEndian<u32>,
+    }
+    impl Header {
+        fn order(&self) -> ByteOrder {
+            match &self.order {
+                b"MM" => ByteOrder::Big,
+                _ => ByteOrder::Little,
+            }
+        }
+    }
+
+    let be: Header = unsafe { transmute(*b"MM\x00\x2a\x00\x00\x00\x08") };
+    let le: Header = unsafe { transmute(*b"II\x2a\x00\x08\x00\x00\x00") };
+    for header in [be, le].iter() {
+        assert_eq!(header.magic.get(header.order()), 42);
+        assert_eq!(header.offset.get(header.order()), 8);
+    }
+
+    let mut value = DynamicEndian::<u64>::new(BE_U64, ByteOrder::Big);
+    assert_eq!(value.to_bytes(), DATA_64);
+    assert_eq!(value.get(ByteOrder::Little), LE_U64);
+    value.set(BE_U64, ByteOrder::Little);
+    assert_eq!(value.get(ByteOrder::Big), LE_U64);
+    let value = DynamicEndian::<f64>::new(-1.5, ByteOrder::Little);
+    assert_eq!(value.to_bytes(), [0, 0, 0, 0, 0, 0, 0xf8, 0xbf]);
+    assert_eq!(
+        DynamicEndian::<u32>::new(1, ByteOrder::NATIVE).to_bytes(),
+        1u32.to_ne_bytes()
+    );
+}
+
+#[test]
+fn byte_order_detection() {
+    let mut cfb = [0; 32];
+    cfb[..8].copy_from_slice(&[0xd0, 0xcf, 0x11, 0xe0, 0xa1, 0xb1, 0x1a, 0xe1]);
+    cfb[28..30].copy_from_slice(&[0xfe, 0xff]);
+    let headers: [(&[u8], _); 14] = [
+        (b"II\x2a\x00", Some(ByteOrder::Little)),
+        (b"MM\x00\x2b", Some(ByteOrder::Big)),
+        (b"\x7fELF\x01\x02", Some(ByteOrder::Big)),
+        (b"\x7fELF\x01\x03", None),
+        (b"\xd4\xc3\xb2\xa1\x02\x00", Some(ByteOrder::Little)),
+        (b"\xa1\xb2\x3c\x4d", Some(ByteOrder::Big)),
+        (
+            b"\x0a\x0d\x0d\x0a\x1c\x00\x00\x00\x4d\x3c\x2b\x1a",
+            Some(ByteOrder::Little),
+        ),
+        (b"\x0a\x0d\x0d\x0a\x00\x00\x00\x1c", None),
+        (&cfb, Some(ByteOrder::Little)),
+        (&cfb[..29], None),
+        (b"\xfe\xff\x00h", Some(ByteOrder::Big)),
+        (b"\xff\xfeh\x00", Some(ByteOrder::Little)),
+        (b"\xfe", None),
+        (b"", None),
+    ];
+    for (header, order) in headers {
+        assert_eq!(ByteOrder::detect(header), order, "{:x?}", header);
+    }
+    assert_eq!(
+        ByteOrder::from_marker(&[1, 2, 3], &[2, 1]),
+        Some(ByteOrder::Little)
+    );
+    assert_eq!(ByteOrder::from_marker(&[1, 2, 3], &[1, 3]), None);
+
+    let elf = b"\x7fELF\x02\x02\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x02\x00\x3e";
+    let mut reader = DynamicReader::detect(elf).unwrap();
+    assert_eq!(reader.order(), ByteOrder::Big);
+    reader.get_mut().skip(16).unwrap();
+    assert_eq!(reader.read::<u16>(), Ok(2));
+    reader.set_order(ByteOrder::Little);
+    assert_eq!(reader.read::<u16>(), Ok(0x3e00));
+    assert!(reader.read::<u8>().is_err());
+    assert!(reader.into_inner().is_empty());
+}
+
+#[test]
+fn pdp_endian() {
+    let value = PDPu32::from_bytes([0x0b, 0x0a, 0x0d, 0x0c]);
+    assert_eq!(value, 0x0a0b_0c0du32);
+    assert_eq!(
+        PDPu32::new(0x0a0b_0c0d).to_bytes(),
+        [0x0b, 0x0a, 0x0d, 0x0c]
+    );
+    assert_eq!(format!("{:x}", value + 1), "a0b0c0e");
+    assert_eq!(BEu32::from(value).to_bytes(), [0x0a, 0x0b, 0x0c, 0x0d]);
+    assert_eq!(PDPu32::from(LEu32::new(0x0a0b_0c0d)), value);
+    assert!(value < BEu32::new(0x0a0b_0c0e));
+
+    let value = PDPi32::from_bytes(DATA_32);
+    assert_eq!(value, 0xa1a0_a3a2u32 as i32);
+    assert_eq!(value, 0xa1a0_a3a2u32 as i32 as i64);
+    assert_eq!(PDPi32::new(-2).to_bytes(), [0xff, 0xff, 0xfe, 0xff]);
+}
+
+#[test]
+fn word_swapped() {
+    let value = CDABu32::from_bytes([0x0c, 0x0d, 0x0a, 0x0b]);
+    assert_eq!(value, 0x0a0b_0c0du32);
+    assert_eq!(
+        CDABu32::new(0x0a0b_0c0d).to_bytes(),
+        [0x0c, 0x0d, 0x0a, 0x0b]
+    );
+    assert_eq!(BADCu32::from(value).to_bytes(), [0x0b, 0x0a, 0x0d, 0x0c]);
+    assert_eq!(CDABf32::new(1.0).to_bytes(), [0x00, 0x00, 0x3f, 0x80]);
+    assert_eq!(BADCf32::new(1.0).to_bytes(), [0x80, 0x3f, 0x00, 0x00]);
+
+    let value = CDABu64::from_bytes(DATA_64);
+    assert_eq!(value, 0xa6a7_a4a5_a2a3_a0a1u64);
+    assert_eq!(value.to_bytes(), DATA_64);
+    assert_eq!(BADCu64::from_bytes(DATA_64), 0xa1a0_a3a2_a5a4_a7a6u64);
+    assert_eq!(
+        CDABi64::new(-2).to_bytes(),
+        [0xff, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+    assert_eq!(
+        CDABf64::new(-0.5).to_bytes(),
+        [0, 0, 0, 0, 0, 0, 0xbf, 0xe0]
+    );
+}
+
+#[test]
+fn bit_fields() {
+    let value = BEu16::from_bytes([0b1010_0000, 0b0000_0001]);
+    assert!(value.bit(0, BitOrder::Msb0));
+    assert!(!value.bit(1, BitOrder::Msb0));
+    assert!(value.bit(0, BitOrder::Lsb0));
+    assert!(value.bit(15, BitOrder::Lsb0));
+    assert_eq!(value.bits(0, 4, BitOrder::Msb0), 0b1010);
+    assert_eq!(value.bits(12, 4, BitOrder::Lsb0), 0b1010);
+    assert_eq!(value.bits(0, 16, BitOrder::Lsb0), value.get());
+
+    let mut value = LEu32::new(0);
+    value.set_bits(0, 3, BitOrder::Msb0, 0b111);
+    assert_eq!(value, 0xe000_0000u32);
+    value.set_bits(4, 8, BitOrder::Lsb0, 0x1ab);
+    assert_eq!(value, 0xe000_0ab0u32);
+    value.set_bits(0, 32, BitOrder::Msb0, 1);
+    assert_eq!(value, 1u32);
+}
+
+#[test]
+#[should_panic]
+fn bit_fields_out_of_range() {
+    BEu8::new(0).bits(4, 5, BitOrder::Msb0);
+}
+
+#[test]
+fn bcd() {
+    use core::convert::TryFrom;
+
+    assert_eq!(BEbcd16::new(1234).to_bytes(), [0x12, 0x34]);
+    assert_eq!(LEbcd16::new(1234).to_bytes(), [0x34, 0x12]);
+    assert_eq!(BEbcd8::new(59).to_bytes(), [0x59]);
+    assert_eq!(BEbcd32::from_bytes([0x20, 0x26, 0x10, 0x16]), 20261016);
+    assert_eq!(
+        LEbcd64::new(9999_9999_9999_9999).int_value(),
+        0x9999_9999_9999_9999
+    );
+    let err = BEbcd8::try_from(100).unwrap_err();
+    assert_eq!(err, Error::new(ErrorKind::OutOfRange).values(99, 100));
+    assert_eq!(
+        format!("{}", err),
+        "value out of range (maximum 99, got 100)"
+    );
+    assert_eq!(
+        BigEndian::<Bcd<u128>>::new(u128::from(u64::MAX)).checked_value(),
+        Some(u128::from(u64::MAX))
+    );
+
+    let value = BEbcd16::from_bytes([0x12, 0x3a]);
+    assert_eq!(value.checked_value(), None);
+    assert!(value != 123);
+    assert_eq!(format!("{:?} {:?}", value, BEbcd16::new(42)), "0x123a 42");
+}
+
+#[test]
+fn ip_addresses() {
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
+    let addr = BEu32::from_bytes([192, 168, 0, 1]);
+    assert_eq!(Ipv4Addr::from(addr), Ipv4Addr::new(192, 168, 0, 1));
+    assert_eq!(BEu32::from(Ipv4Addr::LOCALHOST).to_bytes(), [127, 0, 0, 1]);
+    assert_eq!(LEu32::from(Ipv4Addr::LOCALHOST).to_bytes(), [1, 0, 0, 127]);
+
+    let addr = BEu128::from(Ipv6Addr::LOCALHOST);
+    assert_eq!(addr.to_bytes()[15], 1);
+    assert_eq!(Ipv6Addr::from(addr), Ipv6Addr::LOCALHOST);
+}
+
+#[test]
+fn mac_address() {
+    use core::convert::TryFrom;
+
+    let mac = MacAddr::new([0x00, 0x1b, 0x63, 0x84, 0x45, 0xe6]);
+    assert_eq!(format!("{}", mac), "00:1b:63:84:45:e6");
+    assert_eq!(u64::from(mac), 0x001b_6384_45e6);
+    assert_eq!(MacAddr::try_from(0x001b_6384_45e6), Ok(mac));
+    assert_eq!(MacAddr::from_u64(1 << 48), None);
+    assert_eq!(
+        MacAddr::try_from(1 << 48).unwrap_err().actual(),
+        Some(1 << 48)
+    );
+    assert!(mac.is_unicast() && !mac.is_local());
+    assert!(MacAddr::BROADCAST.is_broadcast() && MacAddr::BROADCAST.is_multicast());
+    assert!(mac < MacAddr::BROADCAST);
+    assert_eq!(core::mem::size_of::<MacAddr>(), 6);
+    assert_eq!(core::mem::align_of::<MacAddr>(), 1);
+}
+
+#[test]
+fn uuids() {
+    // EFI System Partition type GUID, as stored in a GPT partition entry
+    let guid = Guid::from_bytes([
+        0x28, 0x73, 0x2a, 0xc1, 0x1f, 0xf8, 0xd2, 0x11, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9,
+        0x3b,
+    ]);
+    assert_eq!(format!("{}", guid), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+    assert_eq!(guid.to_u128(), 0xc12a7328_f81f_11d2_ba4b_00a0c93ec93b);
+
+    let uuid = Uuid::from(guid);
+    assert_eq!(format!("{:?}", uuid), format!("{:?}", guid));
+    assert_eq!(
+        uuid.to_bytes(),
+        [
+            0xc1, 0x2a, 0x73, 0x28, 0xf8, 0x1f, 0x11, 0xd2, 0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e,
+            0xc9, 0x3b
+        ]
+    );
+    assert_eq!(Guid::from(uuid), guid);
+    assert_eq!(
+        uuid.to_fields(),
+        (
+            0xc12a7328,
+            0xf81f,
+            0x11d2,
+            [0xba, 0x4b, 0x00, 0xa0, 0xc9, 0x3e, 0xc9, 0x3b]
+        )
+    );
+    assert_eq!(Uuid::NIL.to_u128(), 0);
+}
+
+#[test]
+fn unaligned() {
+    #[repr(C, packed)]
+    struct Header {
+        tag: u8,
+        len: Unaligned<BEu32>,
+        crc: Unaligned<LEu16>,
+    }
+
+    let mut header: Header =
+        unsafe { transmute::<[u8; 7], Header>([0x01, 0x00, 0x00, 0x01, 0x00, 0x34, 0x12]) };
+    assert_eq!(header.tag, 1);
+    assert_eq!(header.len.get(), 256);
+    assert_eq!(header.crc.get(), 0x1234);
+
+    header.len.set(BEu32::new(7));
+    header.crc.update(|crc| crc + 1);
+    assert_eq!(
+        unsafe { transmute::<Header, [u8; 7]>(header) },
+        [0x01, 0x00, 0x00, 0x00, 0x07, 0x35, 0x12]
+    );
+    assert_eq!(core::mem::align_of::<Unaligned<BEu64>>(), 1);
+    assert_eq!(
+        Unaligned::new(LEu16::new(3)),
+        Unaligned::from(LEu16::new(3))
+    );
+}
+
+#[test]
+fn overflow_behaviour() {
+    use core::num::{Saturating, Wrapping};
+
+    let counter = BigEndian::<Wrapping<u16>>::new(Wrapping(0xffff));
+    assert_eq!(counter.to_bytes(), [0xff, 0xff]);
+    assert_eq!((counter + Wrapping(2)).to_bytes(), [0x00, 0x01]);
+    assert_eq!(counter * counter, Wrapping(1));
+
+    let level = LittleEndian::<Saturating<i8>>::from_bytes([0x7e]);
+    assert_eq!(level + Saturating(10), Saturating(i8::MAX));
+    assert_eq!(
+        (level - LittleEndian::from(Saturating(-128))).value(),
+        Saturating(127)
+    );
+    assert!(level > Saturating(0));
+    assert_eq!(format!("{} {:x}", level, counter), "126 ffff");
+}
+
+#[test]
+fn volatile() {
+    #[repr(C)]
+    struct Registers {
+        status: Volatile<BEu32>,
+        control: Volatile<LEu16>,
+    }
+
+    let regs = Registers {
+        status: Volatile::new(BEu32::new(0x8000_0001)),
+        control: Volatile::from(LEu16::new(0)),
+    };
+    assert_eq!(regs.status.read(), 0x8000_0001);
+    regs.control.write(LEu16::new(0x0100));
+    regs.control.modify(|control| control | 1);
+    assert_eq!(regs.control.read().to_bytes(), [0x01, 0x01]);
+    assert_eq!(format!("{:?}", regs.status), "2147483649");
+}
+
+#[test]
+fn magic() {
+    #[repr(C)]
+    struct Header {
+        magic: Magic<4>,
+        version: BEu32,
+    }
+
+    let header: Header =
+        unsafe { transmute::<[u8; 8], Header>([0x7f, b'E', b'L', b'F', 0, 0, 0, 1]) };
+    assert_eq!(header.magic, b"\x7fELF");
+    assert!(header.magic != *b"\x7fELG");
+    assert_eq!(header.version, 1);
+    assert_eq!(header.magic, Magic::new(b"\x7fELF"));
+    assert_eq!(format!("{:?}", header.magic), r#"b"\x7fELF""#);
+    assert_eq!(format!("{}", Magic::from(*b"PK\x03\x04")), r"PK\x03\x04");
+}
+
+#[test]
+fn fixed_strings() {
+    let name = FixedStr::<8>::new("boot").unwrap();
+    assert_eq!(name.raw(), b"boot\0\0\0\0");
+    assert_eq!(name.as_str(), Ok("boot"));
+    assert_eq!(name, "boot");
+
+    let label = FixedStr::<11>::with_padding("NO NAME", b' ').unwrap();
+    assert_eq!(label.raw(), b"NO NAME    ");
+    assert_eq!(label, "NO NAME");
+    assert_eq!(FixedStr::<2>::new("abc"), None);
+
+    let garbage = FixedStr::from(*b"ab\0cd\xff");
+    assert_eq!(garbage.trimmed(), b"ab");
+    let invalid = FixedStr::from(*b"a\xffb ");
+    assert!(invalid.as_str().is_err());
+    assert_eq!(format!("{:?} {}", name, invalid), r#""boot" a\xffb"#);
+}
+
+#[test]
+fn arrays() {
+    let mut digest = BigEndian::<[u32; 2]>::new([0x0123_4567, 0x89ab_cdef]);
+    assert_eq!(
+        unsafe { transmute::<BigEndian<[u32; 2]>, [u8; 8]>(digest) },
+        [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]
+    );
+    assert_eq!(digest.value(), [0x0123_4567, 0x89ab_cdef]);
+    assert_eq!(digest.get_at(1), Some(0x89ab_cdef));
+    assert_eq!(digest.get_at(2), None);
+
+    digest.set_at(0, 1);
+    assert_eq!(digest, [1, 0x89ab_cdef]);
+    assert_eq!(<[u32; 2]>::from(digest), [1, 0x89ab_cdef]);
+
+    let table = LittleEndian::from([1u16, 2, 3]);
+    assert_eq!(
+        unsafe { transmute::<LittleEndian<[u16; 3]>, [u8; 6]>(table) },
+        [1, 0, 2, 0, 3, 0]
+    );
+    assert_eq!(format!("{:?}", table), "[1, 2, 3]");
+}
+
+#[test]
+fn array_conversion() {
+    const TABLE: [BEu16; 3] = BEu16::from_native_array([1, 2, 0x0304]);
+    assert_eq!(TABLE[2].to_bytes(), [3, 4]);
+    assert_eq!(BEu16::to_native_array(TABLE), [1, 2, 0x0304]);
+
+    let native: [u16; 3] = TABLE.map_to_native();
+    assert_eq!(native, [1, 2, 0x0304]);
+    let native: [u32; 2] = [LEu32::new(5), LEu32::new(6)].map_to_native();
+    assert_eq!(native, [5, 6]);
+}
+
+#[test]
+fn sentinels() {
+    use core::convert::TryFrom;
+
+    type Cluster = LittleEndian<Sentinel<u32, 0xffff_ffff>>;
+
+    let next = Cluster::from_bytes([0xff; 4]);
+    assert_eq!(next.value(), None);
+    assert!(next.is_none());
+    assert_eq!(next, Cluster::NONE);
+    assert_eq!(Cluster::new(Some(2)).to_bytes(), [2, 0, 0, 0]);
+    assert_eq!(Option::<u32>::from(Cluster::new(Some(2))), Some(2));
+    assert_eq!(Cluster::checked_new(Some(u32::MAX)), None);
+    assert_eq!(Cluster::try_from(None), Ok(Cluster::NONE));
+
+    type Index = BigEndian<Sentinel<i16, 0xffff>>;
+    assert_eq!(Index::new(None).to_bytes(), [0xff, 0xff]);
+    assert_eq!(Index::from_bytes([0xff, 0xfe]), Some(-2));
+    assert_eq!(format!("{:?}", Index::NONE), "None");
+}
+
+#[test]
+fn legacy_signed() {
+    type Ones = BigEndian<OnesComplement<i16>>;
+    type SignMag = LittleEndian<SignMagnitude<i16>>;
+
+    assert_eq!(Ones::new(-1).to_bytes(), [0xff, 0xfe]);
+    assert_eq!(Ones::new(5).to_bytes(), [0x00, 0x05]);
+    assert_eq!(Ones::from_bytes([0x80, 0x00]), -32767);
+    assert_eq!(Ones::from_bytes([0xff, 0xff]).value(), 0);
+    assert_eq!(Ones::checked_new(i16::MIN), None);
+
+    assert_eq!(SignMag::new(-1).to_bytes(), [0x01, 0x80]);
+    assert_eq!(SignMag::new(-32767).to_bytes(), [0xff, 0xff]);
+    assert_eq!(i16::from(SignMag::from_bytes([0x00, 0x80])), 0);
+    assert_eq!(SignMag::from_bytes([0x05, 0x80]).int_value(), -32763);
+    assert_eq!(format!("{:?}", SignMag::from_bytes([0x05, 0x80])), "-5");
+    assert_eq!(
+        BigEndian::<SignMagnitude<i8>>::new(-128i8 + 1).value(),
+        -127
+    );
+}
+
+#[test]
+fn generic_endianness() {
+    fn header<E: Endianness>(magic: u32, len: u16) -> [u8; 6] {
+        let mut buf = [0; 6];
+        E::Wrapper::new(magic).write_into(&mut buf, 0).unwrap();
+        E::Wrapper::new(len).write_into(&mut buf, 4).unwrap();
+        buf
+    }
+    fn parse<E: Endianness>(buf: &[u8]) -> Option<(u32, u16)> {
+        let magic: u32 = E::Wrapper::read_from(buf, 0)?.value();
+        let len: u16 = E::Wrapper::read_from(buf, 4)?.value();
+        Some((magic, len))
+    }
+
+    assert_eq!(header::<BE>(0x0102_0304, 5), [1, 2, 3, 4, 0, 5]);
+    assert_eq!(header::<LE>(0x0102_0304, 5), [4, 3, 2, 1, 5, 0]);
+    assert_eq!(parse::<BE>(&[1, 2, 3, 4, 0, 5]), Some((0x0102_0304, 5)));
+    assert_eq!(parse::<NE>(&header::<NE>(7, 8)), Some((7, 8)));
+    assert_eq!(parse::<LE>(&[0; 5]), None);
+    assert_eq!(BE::ORDER, ByteOrder::Big);
+
+    #[repr(C)]
+    struct Header<E: Endianness> {
+        magic: Endian<u32, E>,
+        len: Endian<u16, E>,
+    }
+    fn len<E: Endianness>(header: &Header<E>) -> u16 {
+        header.len.value()
+    }
+    let header = Header::<LE> {
+        magic: LEu32::new(1),
+        len: EndianInt::new(6),
+    };
+    assert_eq!(len(&header), 6);
+    assert_eq!(header.magic.to_bytes(), [1, 0, 0, 0]);
+    assert_eq!(BigEndian::<u16>::new(2), Endian::<u16, BE>::new(2));
+    assert_eq!(
+        <LE as Endianness>::Wrapper::<f32>::from_bytes([0, 0, 0x80, 0x3f]).value(),
+        1.0
+    );
+}
+
+#[test]
+fn newtypes() {
+    use core::fmt;
+    use core::ops::Add;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct BlockNo(u64);
+    impl Add<u64> for BlockNo {
+        type Output = Self;
+        fn add(self, other: u64) -> Self {
+            BlockNo(self.0 + other)
+        }
+    }
+    impl Add for BlockNo {
+        type Output = Self;
+        fn add(self, other: Self) -> Self {
+            BlockNo(self.0 + other.0)
+        }
+    }
+    impl fmt::Display for BlockNo {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "#{}", self.0)
+        }
+    }
+    crate::endian_convert!(BlockNo(u64));
+
+    let block = LittleEndian::<BlockNo>::new(BlockNo(0x0102));
+    assert_eq!(block.to_bytes(), [2, 1, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(block.value(), BlockNo(0x0102));
+    assert_eq!(block + BlockNo(1), BlockNo(0x0103));
+    assert!(block < LittleEndian::from(BigEndian::from(BlockNo(0x0103))));
+    assert_eq!(format!("{} {:?}", block, block), "#258 BlockNo(258)");
+
+    let mut next = BigEndian::<BlockNo>::from_bytes([0, 0, 0, 0, 0, 0, 0, 1]);
+    next.update(|n| n + 1);
+    assert_eq!(next.to_bytes()[7], 2);
+}
+
+#[test]
+fn raw_equality() {
+    assert_eq!(BEu32::new(0x0102_0304), BEu32::new(0x0102_0304));
+    assert!(LEi16::new(-2) != LEi16::new(2));
+    assert_eq!(
+        BigEndian::<[u16; 2]>::new([1, 2]),
+        BigEndian::<[u16; 2]>::new([1, 2])
+    );
+
+    // Floats still compare by value
+    assert!(BEf32::new(f32::NAN) != BEf32::new(f32::NAN));
+    assert_eq!(BEf64::new(0.0), BEf64::new(-0.0));
+    assert!(BigEndian::<[f32; 1]>::new([f32::NAN]) != BigEndian::<[f32; 1]>::new([f32::NAN]));
+}
+
+#[test]
+fn raw_ordering() {
+    let values = [0u32, 1, 0xff, 0x100, 0x1234_5678, 0x8000_0000, u32::MAX];
+    for &a in &values {
+        for &b in &values {
+            assert_eq!(BEu32::new(a).cmp(&BEu32::new(b)), a.cmp(&b));
+            assert_eq!(LEu32::new(a).partial_cmp(&LEu32::new(b)), a.partial_cmp(&b));
+            assert_eq!(
+                BEu128::new(a as u128 * 3).cmp(&BEu128::new(b as u128 * 3)),
+                a.cmp(&b)
+            );
+        }
+    }
+    // Signed values still compare numerically
+    assert!(BEi16::new(-1) < BEi16::new(1));
+    assert!(LEi64::new(-1) < LEi64::new(1));
+}
+
+#[test]
+fn const_comparisons() {
+    const MAGIC: BEu32 = BEu32::from_bytes(*b"\x7fELF");
+    const _: () = assert!(MAGIC.const_eq_value(0x7f45_4c46));
+    const _: () = assert!(MAGIC.const_eq(BEu32::new(0x7f45_4c46)));
+    const _: () = assert!(!LEf32::new(f32::NAN).const_eq(LEf32::new(f32::NAN)));
+    const _: () = assert!(PdpEndian::<u32>::new(7).const_eq_value(7));
+
+    const TABLE: [BEu16; 3] = BEu16::from_native_array([1, 2, 3]);
+    const SUM: u16 = TABLE[0].value() + TABLE[1].value() + TABLE[2].value();
+    assert_eq!(SUM, 6);
+}
+
+#[test]
+fn literal_macros() {
+    const HEADER: [BEu32; 2] = [be!(0x1337_beefu32), be!(u32::MAX - 1)];
+    assert_eq!(HEADER[0].to_bytes(), [0x13, 0x37, 0xbe, 0xef]);
+    assert_eq!(HEADER[1], 0xffff_fffe);
+    assert_eq!(le!(-2i16).to_bytes(), [0xfe, 0xff]);
+    assert_eq!(le!(1.5f64), 1.5);
+}
+
+#[test]
+fn wide_swaps() {
+    let value = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeffu128;
+    assert_eq!(BEu128::new(value).to_bytes(), value.to_be_bytes());
+    assert_eq!(LEu128::new(value).to_bytes(), value.to_le_bytes());
+    assert_eq!(BEu128::from_bytes(value.to_be_bytes()), value);
+    assert_eq!(LEi128::new(-2).to_bytes(), (-2i128).to_le_bytes());
+    assert_eq!(BEi128::from_bytes(i128::MIN.to_be_bytes()), i128::MIN);
+    assert_eq!(<u128 as Primitive>::to_be_bytes(value), value.to_be_bytes());
+    assert_eq!(
+        <i128 as Primitive>::from_le_bytes((-3i128).to_le_bytes()),
+        -3
+    );
+}
+
+#[test]
+fn raw_bitwise() {
+    let flags = BEu32::new(0x8000_0011);
+    assert_eq!(flags & 0x8000_0000, 0x8000_0000);
+    assert_eq!((flags | 0x0100).to_bytes(), [0x80, 0, 0x01, 0x11]);
+    assert_eq!(flags ^ BEu32::new(0x11), 0x8000_0000);
+    assert_eq!(LEi16::new(-1) & 0x0ff0, 0x0ff0);
+    assert_eq!(
+        BigEndian::<Wrapping<u16>>::new(Wrapping(0xf0f0)) & Wrapping(0xff),
+        Wrapping(0xf0)
+    );
+    let nz = BigEndian::<NonZeroU16>::new(NonZeroU16::new(0x100).unwrap());
+    assert_eq!((nz | NonZeroU16::new(1).unwrap()).value().get(), 0x101);
+    assert_eq!(
+        PdpEndian::<u32>::new(0x1234_5678) & 0xff00_ff00,
+        0x1200_5600
+    );
+}
+
+#[test]
+fn chained_expressions() {
+    let (a, b, c) = (BEu32::new(1), BEu32::new(2), LEu32::new(3));
+    assert_eq!(a + b + c.get(), 6);
+    assert_eq!(a.map(|a| a * 10 + b.get() - c.get()), 9);
+    assert_eq!(BEf64::new(1.5).map(f64::sqrt), 1.5f64.sqrt());
+}
+
+#[test]
+fn slice_casts() {
+    #[repr(align(8))]
+    struct Aligned([u8; 12]);
+    let data = Aligned([0, 0, 0, 1, 0, 0, 0, 2, 0xff, 0xff, 0xff, 0xff]);
+
+    let values = BEu32::slice_from_bytes(&data.0).unwrap();
+    assert_eq!(values, [1, 2, u32::MAX]);
+    assert_eq!(LEu16::slice_from_bytes(&data.0[..4]).unwrap(), [0, 0x100]);
+    assert_eq!(
+        BEu32::slice_from_bytes(&data.0[1..5]),
+        Err(Error::new(ErrorKind::Misaligned).at(0))
+    );
+    assert_eq!(
+        BEu32::slice_from_bytes(&data.0[..6]),
+        Err(Error::truncated(4, 4, 2))
+    );
+    assert_eq!(BEu64::slice_from_bytes(&[]), Ok(&[][..]));
+}
+
+#[test]
+fn slice_casts_mut() {
+    #[repr(align(4))]
+    struct Aligned([u8; 8]);
+    let mut data = Aligned([0, 0, 0, 1, 0, 0, 0, 2]);
+
+    let values = BEu32::slice_from_bytes_mut(&mut data.0).unwrap();
+    values[1].update(|v| v + 0x100);
+    values.swap(0, 1);
+    assert_eq!(data.0, [0, 0, 1, 2, 0, 0, 0, 1]);
+    assert_eq!(
+        LEu32::slice_from_bytes_mut(&mut data.0[2..6]),
+        Err(Error::new(ErrorKind::Misaligned).at(0))
+    );
+    assert_eq!(
+        LEu32::slice_from_bytes_mut(&mut data.0[..7]),
+        Err(Error::truncated(4, 4, 3))
+    );
+}
+
+#[test]
+fn endian_slices() {
+    let data = [0xff, 0, 1, 0, 2, 0xaa];
+    let values = EndianSlice::<u16, BE>::new(&data[1..]);
+    assert_eq!(values.len(), 2);
+    assert_eq!(values.get(0), Some(1));
+    assert_eq!(values.get(2), None);
+    assert_eq!(values.iter().rev().collect::<std::vec::Vec<_>>(), [2, 1]);
+    assert_eq!(
+        format!("{:?}", EndianSlice::<u16, LE>::new(&data[1..])),
+        "[256, 512]"
+    );
+
+    let mut data = [0u8; 7];
+    let mut values = EndianSliceMut::<u32, LE>::new(&mut data[1..]);
+    assert_eq!(values.set(1, 5), None);
+    values.set(0, 0x0403_0201).unwrap();
+    assert_eq!(values.iter().collect::<std::vec::Vec<_>>(), [0x0403_0201]);
+    assert_eq!(data, [0, 1, 2, 3, 4, 0, 0]);
+}
+
+#[test]
+fn byte_iterators() {
+    let data = [0x12, 0x34, 0x56, 0x78, 0x9a];
+    assert!(data.be_u16_iter().eq([0x1234, 0x5678]));
+    assert!(data[1..].le_i16_iter().eq([0x5634, -0x6588]));
+    let mut iter = data.endian_iter::<u32, NE>();
+    assert_eq!(
+        iter.next(),
+        Some(u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]))
+    );
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remainder(), [0x9a]);
+    assert_eq!(data.be_u64_iter().remainder(), data);
+}
+
+#[test]
+fn bulk_swaps() {
+    let mut values: [u32; 7] = core::array::from_fn(|i| 0x0102_0304 * i as u32);
+    let expected = values.map(u32::swap_bytes);
+    swap_slice_in_place(&mut values);
+    assert_eq!(values, expected);
+
+    let mut values: [u16; 9] = core::array::from_fn(|i| 0x1234 + i as u16);
+    let expected = values.map(u16::swap_bytes);
+    swap_slice_in_place(&mut values[..]);
+    assert_eq!(values, expected);
+
+    let mut values = [u128::MAX - 1, 7, 0x0011_2233_4455_6677_8899_aabb_ccdd_eeff];
+    let expected = values.map(u128::swap_bytes);
+    swap_slice_in_place(&mut values);
+    assert_eq!(values, expected);
+
+    let mut values = BEu64::from_native_array([1, 2, 3]);
+    let native = BEu64::slice_to_native(&mut values);
+    assert_eq!(native, [1, 2, 3]);
+    native[1] = 5;
+    assert_eq!(BEu64::slice_from_native(native), [1, 5, 3]);
+    let mut floats = [1.5f32, -2.0];
+    assert_eq!(LEf32::slice_from_native(&mut floats), [1.5, -2.0]);
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+#[test]
+fn bulk_swap_kernels() {
+    let src: [u8; 40] = core::array::from_fn(|i| i as u8);
+    for size in [2, 4, 8, 16] {
+        let mut expected = src;
+        expected[..32]
+            .chunks_exact_mut(size)
+            .for_each(|value| value.reverse());
+        let mut dst = [0; 40];
+        dst[32..].copy_from_slice(&src[32..]);
+        if std::is_x86_feature_detected!("sse2") {
+            let done =
+                unsafe { bulk::x86::swap_blocks_sse2(src.as_ptr(), dst.as_mut_ptr(), 40, size) };
+            assert_eq!((done, dst), (32, expected), "SSE2, {} bytes", size);
+        }
+        if std::is_x86_feature_detected!("ssse3") {
+            dst[..32].fill(0);
+            let done =
+                unsafe { bulk::x86::swap_blocks_ssse3(src.as_ptr(), dst.as_mut_ptr(), 40, size) };
+            assert_eq!((done, dst), (32, expected), "SSSE3, {} bytes", size);
+        }
+    }
+}
+
+#[test]
+fn bulk_copies() {
+    let src: [BEu32; 11] = core::array::from_fn(|i| BEu32::new(0x0102_0304 * i as u32));
+    let mut native = [0u32; 11];
+    BEu32::copy_to_native(&mut native, &src);
+    assert!(native
+        .iter()
+        .enumerate()
+        .all(|(i, &v)| v == 0x0102_0304 * i as u32));
+
+    let mut dst = [LEu16::new(0); 11];
+    let src: [u16; 11] = core::array::from_fn(|i| 0x1234 + i as u16);
+    LEu16::copy_from_native(&mut dst, &src);
+    assert_eq!(dst, src);
+    let mut dst = [NEf64::new(0.0); 2];
+    NEf64::copy_from_native(&mut dst, &[1.5, -0.5]);
+    assert_eq!(dst, [1.5, -0.5]);
+
+    // Several whole SIMD blocks and a scalar tail for the wider types
+    let src: [u64; 5] = core::array::from_fn(|i| 0x0102_0304_0506_0708 << i);
+    let mut dst = [BEu64::new(0); 5];
+    BEu64::copy_from_native(&mut dst, &src);
+    assert_eq!(dst, src);
+    assert_eq!(dst[0].to_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut native = [0; 5];
+    BEu64::copy_to_native(&mut native, &dst);
+    assert_eq!(native, src);
+    let src: [i128; 3] =
+        core::array::from_fn(|i| -0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10 * i as i128);
+    let mut dst = [BEi128::new(0); 3];
+    BEi128::copy_from_native(&mut dst, &src);
+    assert_eq!(dst, src);
+}
+
+#[test]
+#[should_panic]
+fn bulk_copy_length_mismatch() {
+    BEu64::copy_to_native(&mut [0; 2], &[BEu64::new(1)]);
+}
+
+#[test]
+fn aggregates() {
+    let values = BEu32::from_native_array([5, 0x100, 1, 0xff]);
+    assert_eq!(BEu32::slice_sum(&values), 0x205);
+    assert_eq!(BEu32::slice_min(&values), Some(1));
+    assert_eq!(BEu32::slice_max(&values), Some(0x100));
+    assert!(BEu32::slice_contains(&values, 0xff));
+    assert!(!BEu32::slice_contains(&values, 0xff00_0000));
+    assert_eq!(BEu32::slice_max(&[]), None);
+
+    let values = LEi16::from_native_array([-5, 3, -0x100]);
+    assert_eq!(LEi16::slice_min(&values), Some(-0x100));
+    assert_eq!(LEi16::slice_max(&values), Some(3));
+    let floats = BEf64::from_native_array([0.5, -0.0]);
+    assert_eq!(BEf64::slice_sum(&floats), 0.5);
+    assert!(BEf64::slice_contains(&floats, 0.0));
+}
+
+#[test]
+fn binary_search() {
+    let index = BEu64::from_native_array([1, 3, 0x100, 0x1_0000_0000, u64::MAX]);
+    assert_eq!(BEu64::slice_binary_search(&index, 0x100), Ok(2));
+    assert_eq!(BEu64::slice_binary_search(&index, 0xff), Err(2));
+    assert_eq!(BEu64::slice_binary_search(&index, 0), Err(0));
+    assert_eq!(BEu64::slice_partition_point(&index, |v| v < 0x1000), 3);
+
+    let signed = LEi32::from_native_array([-300, -1, 0, 7]);
+    assert_eq!(LEi32::slice_binary_search(&signed, -1), Ok(1));
+    assert_eq!(LEi32::slice_partition_point(&signed, |v| v < 0), 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vectors() {
+    use std::vec;
+
+    let bytes = vec![0, 0, 0, 1, 0, 0, 0, 2];
+    let values = BEu32::vec_from_bytes(&bytes[..]).unwrap();
+    assert_eq!(values, [1, 2]);
+    assert_eq!(
+        BEu32::vec_from_bytes(&bytes[1..]),
+        Err(Error::truncated(4, 4, 3))
+    );
+    assert_eq!(BEu32::vec_to_bytes(&values), bytes);
+
+    let native = BEu32::into_native_vec(values);
+    assert_eq!(native, [1, 2]);
+    let values = LEu32::from_native_vec(native);
+    assert_eq!(LEu32::vec_to_bytes(&values), [1, 0, 0, 0, 2, 0, 0, 0]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn endian_buf() {
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct Header {
+        magic: BEu32,
+        count: LEu16,
+        flags: BEu16,
+    }
+
+    let header = Header {
+        magic: BEu32::new(0x1337_beef),
+        count: LEu16::new(2),
+        flags: BEu16::new(1),
+    };
+    let mut buf = EndianBuf::with_capacity(16);
+    // SAFETY: `Header` has no padding
+    unsafe { buf.push_struct(&header) };
+    buf.push(BEu16::new(0x0102)).push_u8(0xff).push_le_i16(-2);
+    buf.push_endian::<u16, BE>(3);
+    assert_eq!(buf.len(), 15);
+    assert_eq!(
+        buf.into_vec(),
+        [0x13, 0x37, 0xbe, 0xef, 2, 0, 0, 1, 1, 2, 0xff, 0xfe, 0xff, 0, 3]
+    );
+}
+
+#[test]
+fn radix_sorting() {
+    // Simple xorshift, to get enough values to exercise every radix pass
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut values: [BEu32; 1000] =
+        core::array::from_fn(|_| BEu32::new(next() as u32 & 0xff0f_ffff));
+    let mut expected = BEu32::to_native_array(values);
+    expected.sort_unstable();
+    BEu32::slice_radix_sort(&mut values, &mut [BEu32::new(0); 1000]);
+    assert_eq!(BEu32::to_native_array(values), expected);
+
+    let mut values: [LEu64; 500] = core::array::from_fn(|_| LEu64::new(next() >> 40));
+    let mut expected = LEu64::to_native_array(values);
+    expected.sort_unstable();
+    LEu64::slice_radix_sort(&mut values, &mut [LEu64::new(0); 600]);
+    assert_eq!(LEu64::to_native_array(values), expected);
+
+    let mut values: [BEi16; 300] = core::array::from_fn(|_| BEi16::new(next() as i16));
+    let mut expected = BEi16::to_native_array(values);
+    expected.sort_unstable();
+    BEi16::slice_radix_sort(&mut values, &mut [BEi16::new(0); 300]);
+    assert_eq!(BEi16::to_native_array(values), expected);
+
+    let mut values = LEi32::from_native_array([3, -1, i32::MIN, 0, i32::MAX, -300]);
+    LEi32::slice_radix_sort(&mut values, &mut [LEi32::new(0); 6]);
+    assert_eq!(
+        LEi32::to_native_array(values),
+        [i32::MIN, -300, -1, 0, 3, i32::MAX]
+    );
+
+    // Sorting by key is stable
+    let mut records: [(BEu16, usize); 200] =
+        core::array::from_fn(|i| (BEu16::new(next() as u16 % 50), i));
+    BEu16::slice_radix_sort_by_key(&mut records, &mut [(BEu16::new(0), 0); 200], |record| {
+        record.0
+    });
+    assert!(records
+        .windows(2)
+        .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+
+    // Large slices are split on their most significant byte first, with small parts insertion sorted
+    let mut values: std::vec::Vec<LEi32> = (0..30_000).map(|_| LEi32::new(next() as i32)).collect();
+    let mut expected: std::vec::Vec<i32> = values.iter().map(|value| value.value()).collect();
+    expected.sort_unstable();
+    let mut scratch = values.clone();
+    LEi32::slice_radix_sort(&mut values, &mut scratch);
+    assert!(values.iter().map(|value| value.value()).eq(expected));
+
+    let mut records: std::vec::Vec<(LEu64, usize)> = (0..20_000)
+        .map(|i| (LEu64::new((next() % 3000) << 20), i))
+        .collect();
+    let mut scratch = records.clone();
+    LEu64::slice_radix_sort_by_key(&mut records, &mut scratch, |record| record.0);
+    assert!(records
+        .windows(2)
+        .all(|w| (w[0].0.value(), w[0].1) < (w[1].0.value(), w[1].1)));
+}
+
+#[test]
+fn filters() {
+    use std::vec::Vec;
+
+    let column: [BEu32; 11] = core::array::from_fn(|i| BEu32::new([7, 0x0700_0000, 3][i % 3]));
+    let found: Vec<_> = BEu32::slice_find_eq(&column, 7).collect();
+    assert_eq!(found, [0, 3, 6, 9]);
+    let found: Vec<_> = BEu32::slice_find_eq(&column[1..], 3).collect();
+    assert_eq!(found, [1, 4, 7]);
+    assert_eq!(BEu32::slice_find_eq(&column, 0x0007_0000).count(), 0);
+
+    let column = LEu16::from_native_array([1, 0x100, 1, 1, 2, 1, 1, 1, 1, 0x101]);
+    assert!(LEu16::slice_find_eq(&column, 1).eq([0, 2, 3, 5, 6, 7, 8]));
+    let column = BEi64::from_native_array([-1, 5, -1]);
+    assert!(BEi64::slice_find_eq(&column, -1).eq([0, 2]));
+    let column = [BEu8::new(1); 20];
+    assert_eq!(BEu8::slice_find_eq(&column, 1).count(), 20);
+
+    let column = BEu32::from_native_array([5, 0x100, 1, 0xff, 0x1_0000]);
+    assert!(BEu32::slice_find_in_range(&column, 2..=0x100).eq([0, 1, 3]));
+    assert!(BEu32::slice_find_in_range(&column, 0x100..).eq([1, 4]));
+    let column = LEi16::from_native_array([-5, 3, -0x100]);
+    assert!(LEi16::slice_find_in_range(&column, ..0).eq([0, 2]));
+
+    // Whole blocks (compared with SIMD where available) against comparing each value
+    fn check<T: Primitive + Ord, E: Endianness>(native: &[T], bounds: &[T])
+    where
+        Endian<T, E>: Ord,
+    {
+        use core::ops::Bound::{self, *};
+        use core::ops::RangeBounds;
+
+        let column: Vec<Endian<T, E>> = native.iter().map(|&value| EndianInt::new(value)).collect();
+        let mut ranges: Vec<(Bound<T>, Bound<T>)> = Vec::new();
+        for &a in bounds {
+            ranges.extend([(Included(a), Unbounded), (Excluded(a), Unbounded)]);
+            ranges.extend([(Unbounded, Included(a)), (Unbounded, Excluded(a))]);
+            for &b in bounds {
+                ranges.extend([(Included(a), Included(b)), (Excluded(a), Excluded(b))]);
+            }
+        }
+        for range in ranges {
+            let expected = native
+                .iter()
+                .enumerate()
+                .filter(|(_, value)| range.contains(*value));
+            assert!(
+                Endian::<T, E>::slice_find_in_range(&column, range).eq(expected.map(|(i, _)| i)),
+                "{:?} in {:?}",
+                range,
+                native
+            );
+        }
+    }
+    let native: Vec<u32> = (0..37)
+        .map(|i| (i * 0x0123_4567u32).rotate_left(i))
+        .collect();
+    check::<u32, BE>(&native, &[0, 1, 0x8000_0000, native[5], u32::MAX]);
+    check::<u32, LE>(&native, &[0, 0x7fff_ffff, native[9], u32::MAX]);
+    let native: Vec<i32> = native.iter().map(|&value| value as i32).collect();
+    check::<i32, BE>(&native, &[i32::MIN, -1, 0, native[3], i32::MAX]);
+    let native: Vec<u16> = (0..50u16).map(|i| i.wrapping_mul(0x1357)).collect();
+    check::<u16, BE>(&native, &[0, 0xff, 0x100, 0x8000, native[7]]);
+    let native: Vec<i16> = native.iter().map(|&value| value as i16).collect();
+    check::<i16, LE>(&native, &[i16::MIN, -0x100, 0, 0xff]);
+    let native: Vec<u8> = (0..=255).collect();
+    check::<u8, BE>(&native, &[0, 0x7f, 0x80, 0xff]);
+    let native: Vec<i8> = (-128..=127).collect();
+    check::<i8, LE>(&native, &[-128, -1, 0, 127]);
+    let native: Vec<u64> = (0..9).map(|i| 0x0102_0304_0506_0708 << i).collect();
+    check::<u64, BE>(&native, &[0, native[3], u64::MAX]);
+}
+
+#[test]
+fn readers() {
+    let data = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut reader = EndianReader::new(&data);
+    assert_eq!(reader.read::<BEu16>(), Ok(BEu16::new(0x0102)));
+    assert_eq!(reader.read::<LEu32>().map(LEu32::value), Ok(0x0605_0403));
+    assert_eq!(reader.position(), 6);
+    assert_eq!(reader.remaining(), 3);
+    assert_eq!(reader.read::<BEu32>(), Err(Error::truncated(6, 4, 3)));
+    assert_eq!(reader.position(), 6);
+    assert_eq!(reader.read::<i8>(), Ok(7));
+    assert!(reader.read::<CDABu32>().is_err());
+    reader.skip(1).unwrap();
+    assert_eq!(reader.remaining_bytes(), [9]);
+    assert_eq!(reader.read_bytes(1), Ok(&[9][..]));
+    assert!(reader.is_empty());
+    assert_eq!(reader.read_bytes(0), Ok(&[][..]));
+}
+
+#[test]
+fn byte_sources() {
+    // Delivers one byte at a time
+    struct Trickle<'a>(&'a [u8]);
+    impl ByteSource for Trickle<'_> {
+        fn fill(&mut self) -> &[u8] {
+            &self.0[..self.0.len().min(1)]
+        }
+        fn consume(&mut self, len: usize) {
+            self.0 = &self.0[len..];
+        }
+    }
+
+    let mut reader = EndianReader::from_source(Trickle(&[1, 2, 3, 4, 5, 6, 7]));
+    assert_eq!(reader.read::<BEu32>(), Ok(BEu32::new(0x0102_0304)));
+    reader.skip(1).unwrap();
+    assert_eq!(reader.read::<LEu32>(), Err(Error::truncated(5, 4, 2)));
+    assert_eq!(reader.position(), 7);
+    assert!(reader.at_end());
+    assert!(reader.read_into(&mut []).is_ok());
+    assert!(reader.into_inner().0.is_empty());
+
+    let mut reader = EndianReader::from_source(Trickle(&[0xac, 0x02, 0x7e, 0x80]));
+    assert_eq!(reader.read_uleb128(), Ok(300));
+    assert_eq!(reader.read_sleb128(), Ok(-2));
+    assert_eq!(
+        reader.read_uleb128(),
+        Err(Error::new(ErrorKind::Truncated).at(3))
+    );
+
+    let mut reader = EndianReader::from_source(&[1, 2, 3][..]);
+    let mut buf = [0; 2];
+    reader.read_into(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(*reader.get_ref(), [3]);
+}
+
+#[test]
+fn chained_sources() {
+    let segments: [&[u8]; 4] = [&[1, 2, 3], &[], &[4], &[5, 6, 7, 8, 9]];
+    let source = segments[0]
+        .chain(segments[1])
+        .chain(segments[2])
+        .chain(segments[3]);
+    assert_eq!(source.remaining(), Some(9));
+    let mut reader = EndianReader::from_source(source);
+    assert_eq!(reader.read::<LEu32>(), Ok(LEu32::new(0x0403_0201)));
+    assert_eq!(reader.read::<u8>(), Ok(5));
+    assert_eq!(reader.read::<BEu64>(), Err(Error::truncated(5, 8, 4)));
+    assert_eq!(reader.position(), 5);
+    assert_eq!(reader.read::<BEu32>(), Ok(BEu32::new(0x0607_0809)));
+    assert!(reader.at_end());
+    let (rest, last) = reader.into_inner().into_inner();
+    assert!(last.is_empty());
+    assert_eq!(rest.remaining(), Some(0));
+}
+
+#[test]
+fn segmented_sources() {
+    let fragments: [&[u8]; 5] = [&[], &[0x12], &[], &[0x34, 0x56, 0x78, 0x9a], &[0xbc]];
+    let mut reader = EndianReader::from_source(Segments::new(fragments));
+    assert_eq!(reader.read::<BEu16>(), Ok(BEu16::new(0x1234)));
+    assert_eq!(reader.read::<BEu32>(), Ok(BEu32::new(0x5678_9abc)));
+    assert!(reader.at_end());
+    assert!(reader.read::<u8>().is_err());
+
+    let mut reader = EndianReader::from_source(Segments::new(fragments.iter().copied()));
+    reader.skip(2).unwrap();
+    let (current, mut rest) = reader.into_inner().into_inner();
+    assert_eq!(current, [0x56, 0x78, 0x9a]);
+    assert_eq!(rest.next(), Some(&[0xbc][..]));
+}
+
+#[test]
+fn writers() {
+    let mut data = [0xaa; 9];
+    let mut writer = EndianWriter::new(&mut data);
+    writer.write(BEu16::new(0x0102)).unwrap();
+    writer.write(LEu32::new(0x0605_0403)).unwrap();
+    assert_eq!(writer.position(), 6);
+    assert_eq!(writer.remaining(), 3);
+    assert_eq!(writer.write(BEu32::new(0)), Err(Error::truncated(6, 4, 3)));
+    assert_eq!(writer.position(), 6);
+    writer.write(-1i8).unwrap();
+    writer.skip(1).unwrap();
+    assert!(writer.write_bytes(&[1, 2]).is_err());
+    writer.write_bytes(&[9]).unwrap();
+    assert!(writer.is_full());
+    writer.write_bytes(&[]).unwrap();
+    assert_eq!(writer.written(), [1, 2, 3, 4, 5, 6, 0xff, 0xaa, 9]);
+    assert_eq!(writer.into_written().len(), 9);
+}
+
+#[test]
+fn views() {
+    let mut data = [0; 8];
+    let mut view = EndianViewMut::new(&mut data);
+    assert_eq!(view.len(), 8);
+    view.set(1, BEu32::new(0x0102_0304)).unwrap();
+    view.set::<LEu16>(6, LEu16::new(0x0807)).unwrap();
+    assert_eq!(view.get::<BEu16>(6), Ok(BEu16::new(0x0708)));
+    assert_eq!(view.set(5, BEu32::new(0)), Err(Error::truncated(5, 4, 3)));
+    assert!(view.set_bytes(usize::MAX, &[0]).is_err());
+    assert_eq!(view.as_bytes_mut(), [0, 1, 2, 3, 4, 0, 7, 8]);
+
+    let view = EndianView::new(&data);
+    assert_eq!(view.get::<LEu32>(1).map(LEu32::value), Ok(0x0403_0201));
+    assert_eq!(view.get::<u8>(8).unwrap_err().actual(), Some(0));
+    assert_eq!(view.get::<u8>(10).unwrap_err().actual(), Some(0));
+    assert_eq!(view.get_bytes(8, 0), Ok(&[][..]));
+    assert_eq!(view.get_bytes(2, 2), Ok(&[2, 3][..]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_traits() {
+    use std::io::ErrorKind;
+    use std::vec::Vec;
+
+    let mut out = Vec::new();
+    out.write_value(BEu16::new(0x0102)).unwrap();
+    out.write_value(LEi32::new(-2)).unwrap();
+    out.write_value(7u8).unwrap();
+    assert_eq!(out, [1, 2, 0xfe, 0xff, 0xff, 0xff, 7]);
+
+    let mut input = std::io::Cursor::new(out);
+    assert_eq!(input.read_value::<BEu16>().unwrap(), 0x0102);
+    assert_eq!(input.read_value::<LEi32>().unwrap(), -2);
+    let err = input.read_value::<BEu16>().unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    let err = std::io::Error::from(EndianReader::new(&[]).read::<u8>().unwrap_err());
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[cfg(all(feature = "std", unix))]
+#[test]
+fn positioned_reads() {
+    use std::io::{ErrorKind, Seek, Write};
+
+    let path = std::env::temp_dir().join(std::format!("storage_endian_{}", std::process::id()));
+    let mut file = std::fs::File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    file.write_all(b"\x7fELF\x02\x01\x01\x00\x00\x00\x3e\x00")
+        .unwrap();
+    assert_eq!(file.pread_value::<BEu32>(0).unwrap(), 0x7f45_4c46);
+    assert_eq!(file.pread_value::<LEu16>(10).unwrap(), 0x3e);
+    let err = file.pread_value::<LEu32>(10).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    file.rewind().unwrap();
+    assert_eq!(file.read_value_at::<u8>(4).unwrap(), 2);
+    assert!(file.read_value_at::<LEu32>(10).is_err());
+    assert_eq!(file.stream_position().unwrap(), 0);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn bit_streams() {
+    for order in [BitOrder::Msb0, BitOrder::Lsb0] {
+        let fields = [
+            (1, 1),
+            (0x5, 3),
+            (0x1ff, 9),
+            (0, 2),
+            (0x1234_5678_9abc, 48),
+            (u64::MAX, 64),
+            (3, 2),
+        ];
+        let mut buf = [0; 19];
+        let mut bits = BitWriter::new(EndianWriter::new(&mut buf), order);
+        for (value, len) in fields {
+            bits.write_bits(value, len).unwrap();
+        }
+        assert_eq!(bits.bit_position(), 129);
+        bits.write(BEu16::new(0xabcd)).unwrap();
+        assert!(bits.write_bits(0, 8).is_err());
+        assert_eq!(bits.finish().unwrap().position(), 19);
+
+        let mut bits = BitReader::new(EndianReader::new(&buf), order);
+        for (value, len) in fields {
+            assert_eq!(bits.read_bits(len), Ok(value));
+        }
+        assert!(!bits.is_aligned());
+        assert_eq!(bits.read::<BEu16>(), Ok(BEu16::new(0xabcd)));
+        assert!(bits.is_aligned());
+        assert_eq!(bits.read_bits(0), Ok(0));
+        assert!(bits.read_bit().is_err());
+    }
+
+    let mut bits = BitReader::new(EndianReader::new(&[0b1100_0101, 0xff]), BitOrder::Msb0);
+    assert_eq!(bits.read_bits(2), Ok(0b11));
+    assert_eq!(bits.read_bits(4), Ok(0b0001));
+    assert_eq!(bits.read_bits(4), Ok(0b0111));
+    assert_eq!(bits.read_bits(7), Err(Error::truncated(2, 1, 0)));
+    assert_eq!(bits.bit_position(), 10);
+    assert_eq!(bits.read_bits(6), Ok(0x3f));
+
+    let mut bits = BitReader::new(EndianReader::new(&[0b1100_0101, 0xff]), BitOrder::Lsb0);
+    assert_eq!(bits.read_bits(2), Ok(0b01));
+    assert_eq!(bits.read_bits(4), Ok(0b0001));
+    assert_eq!(bits.read_bits(4), Ok(0b1111));
+
+    let mut buf = [0; 1];
+    let mut bits = BitWriter::new(EndianWriter::new(&mut buf), BitOrder::Msb0);
+    bits.write_bits(0b101, 3).unwrap();
+    assert!(bits.write_bits(0, 14).is_err());
+    assert_eq!(bits.bit_position(), 3);
+    bits.write_bits(0b1_1111, 5).unwrap();
+    assert!(bits.is_aligned());
+    assert_eq!(buf, [0b1011_1111]);
+}
+
+#[test]
+fn varints() {
+    let mut buf = [0; MAX_VARINT_LEN];
+    assert_eq!(encode_uleb128(0, &mut buf), [0]);
+    assert_eq!(
+        encode_uleb128(u64::MAX, &mut buf),
+        [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 1]
+    );
+    assert_eq!(encode_sleb128(-123456, &mut buf), [0xc0, 0xbb, 0x78]);
+    assert_eq!(encode_sleb128(63, &mut buf), [0x3f]);
+    assert_eq!(encode_sleb128(64, &mut buf), [0xc0, 0]);
+    assert_eq!(encode_sleb128(-64, &mut buf), [0x40]);
+    assert_eq!(
+        encode_sleb128(i64::MIN, &mut buf),
+        [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]
+    );
+    assert_eq!(encode_vlq(0, &mut buf), [0]);
+    assert_eq!(encode_vlq(0x3fff, &mut buf), [0xff, 0x7f]);
+    assert_eq!(encode_vlq(0x4000, &mut buf), [0x81, 0x80, 0]);
+
+    for value in [0, 1, 63, 64, 127, 128, 0x1234_5678, u64::MAX >> 1, u64::MAX] {
+        let len = encode_uleb128(value, &mut buf).len();
+        assert_eq!(decode_uleb128(&buf), Ok((value, len)));
+        let len = encode_vlq(value, &mut buf).len();
+        assert_eq!(decode_vlq(&buf), Ok((value, len)));
+        for value in [value as i64, !value as i64] {
+            let len = encode_sleb128(value, &mut buf).len();
+            assert_eq!(decode_sleb128(&buf), Ok((value, len)));
+        }
+    }
+
+    assert_eq!(
+        decode_uleb128(&[0x80, 0x80]),
+        Err(Error::new(ErrorKind::Truncated).at(0))
+    );
+    assert_eq!(
+        decode_uleb128(
+            &[0xff; 9]
+                .iter()
+                .chain(&[2])
+                .copied()
+                .collect::<std::vec::Vec<_>>()
+        ),
+        Err(Error::new(ErrorKind::OutOfRange).at(0))
+    );
+    assert_eq!(
+        decode_uleb128(&[0x80; 11]),
+        Err(Error::new(ErrorKind::OutOfRange).at(0))
+    );
+    assert_eq!(
+        decode_sleb128(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x3f]),
+        Err(Error::new(ErrorKind::OutOfRange).at(0))
+    );
+    assert_eq!(
+        decode_vlq(&[0x82, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0]),
+        Err(Error::new(ErrorKind::OutOfRange).at(0))
+    );
+    assert_eq!(
+        decode_vlq(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0]),
+        Ok((1 << 63, 10))
+    );
+    assert_eq!(decode_vlq(&[]), Err(Error::new(ErrorKind::Truncated).at(0)));
+
+    let mut data = [0; 16];
+    let mut writer = EndianWriter::new(&mut data);
+    writer.write_uleb128(300).unwrap();
+    writer.write(BEu16::new(7)).unwrap();
+    writer.write_sleb128(-2).unwrap();
+    writer.write_vlq(200).unwrap();
+    assert!(writer.write_uleb128(u64::MAX).is_err());
+    assert_eq!(writer.written(), [0xac, 0x02, 0, 7, 0x7e, 0x81, 0x48]);
+
+    let mut reader = EndianReader::new(&data[..7]);
+    assert_eq!(reader.read_uleb128(), Ok(300));
+    assert_eq!(reader.read::<BEu16>(), Ok(BEu16::new(7)));
+    assert_eq!(reader.read_sleb128(), Ok(-2));
+    assert_eq!(reader.position(), 5);
+    let mut reader = EndianReader::new(&data[5..6]);
+    assert_eq!(
+        reader.read_vlq(),
+        Err(Error::new(ErrorKind::Truncated).at(0))
+    );
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn zigzag() {
+    for (value, encoded) in [
+        (0, 0),
+        (-1, 1),
+        (1, 2),
+        (-2, 3),
+        (i32::MAX, u32::MAX - 1),
+        (i32::MIN, u32::MAX),
+    ] {
+        assert_eq!(value.zigzag_encode(), encoded);
+        assert_eq!(i32::zigzag_decode(encoded), value);
+        assert_eq!((value as i64).zigzag_encode(), encoded as u64);
+    }
+    assert_eq!(i8::MIN.zigzag_encode(), 0xff);
+    assert_eq!(i128::zigzag_decode(u128::MAX - 1), i128::MAX);
+    assert_eq!(isize::zigzag_decode(5), -3);
+
+    let mut data = [0; 13];
+    let mut writer = EndianWriter::new(&mut data);
+    writer.write_zigzag(-1).unwrap();
+    writer.write_zigzag(150).unwrap();
+    writer.write_zigzag(i64::MIN).unwrap();
+    assert!(writer.is_full());
+    let mut reader = EndianReader::new(&data);
+    assert_eq!(reader.read_zigzag(), Ok(-1));
+    assert_eq!(reader.read_zigzag(), Ok(150));
+    assert_eq!(reader.read_zigzag(), Ok(i64::MIN));
+    assert_eq!(&data[..3], [1, 0xac, 0x02]);
+}
+
+#[test]
+fn errors() {
+    use std::string::ToString;
+
+    let error = EndianReader::new(&[1]).read::<BEu32>().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Truncated);
+    assert_eq!(
+        (error.offset(), error.expected(), error.actual()),
+        (Some(0), Some(4), Some(1))
+    );
+    assert_eq!(
+        error.to_string(),
+        "data truncated at offset 0 (expected 4, got 1)"
+    );
+
+    let error = BEu32::slice_from_bytes(&[0; 3]).unwrap_err();
+    assert_eq!(error, Error::new(ErrorKind::Truncated).at(0).values(4, 3));
+    let error = BEu24::try_from(0x0100_0000u32).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfRange);
+    assert_eq!(error.offset(), None);
+    let error = BEu16::try_from(&[0u8; 3][..]).unwrap_err();
+    assert_eq!(
+        (error.kind(), error.expected(), error.actual()),
+        (ErrorKind::TooLong, Some(2), Some(3))
+    );
+    assert_eq!(
+        error.to_string(),
+        "unexpected trailing data (expected 2, got 3)"
+    );
+    let error = decode_uleb128(&[0x80]).unwrap_err();
+    assert_eq!(error.to_string(), "data truncated at offset 0");
+    // Varint errors are reported at the reader's position, like fixed-width ones
+    let mut reader = EndianReader::new(&[
+        0, 1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    ]);
+    let mut parse = || -> Result<u64, Error> {
+        Ok(reader.read::<BEu16>()?.value() as u64 + reader.read_uleb128()?)
+    };
+    let error = parse().unwrap_err();
+    assert_eq!(
+        (error.kind(), error.offset()),
+        (ErrorKind::OutOfRange, Some(2))
+    );
+
+    let mut reader = EndianReader::new(b"\x7fELF\x7fELG");
+    assert_eq!(reader.expect_magic(b"\x7fELF"), Ok(()));
+    let error = reader.expect_magic(b"\x7fELF").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "bad magic number at offset 4 (expected 0x7f454c46, got 0x7f454c47)"
+    );
+    let mut reader = EndianReader::new(&[0; 9]);
+    let error = reader.expect_magic(&[1; 9]).unwrap_err();
+    assert_eq!(
+        (error.kind(), error.expected()),
+        (ErrorKind::BadMagic, None)
+    );
+    assert_eq!(
+        reader.expect_magic(&[0]).unwrap_err().kind(),
+        ErrorKind::Truncated
+    );
+}
+
+#[test]
+fn fields() {
+    type Kind = Field<u8, 0>;
+    type Length = Field<BEu16, 1>;
+    type Checksum = Field<LEu32, 3>;
+
+    assert_eq!((Length::OFFSET, Length::SIZE, Length::END), (1, 2, 3));
+    let mut header = [0; 7];
+    Kind::set(&mut header, 9);
+    Length::set(&mut header, BEu16::new(0x0102));
+    Checksum::set(&mut header, LEu32::new(0x0605_0403));
+    assert_eq!(header, [9, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(Checksum::get(&header), 0x0605_0403);
+
+    let bytes = &header[..5];
+    assert_eq!(Length::read(bytes), Ok(BEu16::new(0x0102)));
+    assert_eq!(Checksum::read(bytes).unwrap_err().actual(), Some(2));
+    assert!(Checksum::write(&mut header[..6], LEu32::new(0)).is_err());
+    assert_eq!(Kind::write(&mut header, 1), Ok(()));
+    assert_eq!(header[0], 1);
+}
+
+#[test]
+fn tlv_records() {
+    use std::vec::Vec;
+
+    let records = [1, 2, 0xaa, 0xbb, 2, 0, 3, 1, 0xcc];
+    let parsed: Result<Vec<_>, _> = Tlv::<u8, u8>::new(&records).collect();
+    let parsed = parsed.unwrap();
+    assert_eq!(
+        parsed,
+        [(1, &[0xaa, 0xbb][..]), (2, &[][..]), (3, &[0xcc][..])]
+    );
+
+    let records = [1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+    let mut tlv = Tlv::<LEu16, LEu32>::new(&records[..5]);
+    let error = tlv.next().unwrap().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Truncated);
+    assert_eq!(error.offset(), Some(2));
+    assert_eq!(tlv.offset(), 0);
+    assert_eq!(tlv.remaining_bytes(), &records[..5]);
+    assert!(tlv.next().is_none());
+    let mut tlv = Tlv::<LEu16, LEu32>::new(&records);
+    assert_eq!(tlv.next().unwrap().unwrap_err().offset(), Some(6));
+
+    assert_eq!(BEu16::from_len(0x1_0000), None);
+    assert_eq!(LEu32::from_len(7).and_then(LEu32::to_len), Some(7));
+    assert_eq!(BEu128::new(u128::MAX).to_len(), None);
+}
+
+#[test]
+fn length_prefixed_frames() {
+    let framing = LengthPrefix::<LEu32>::new();
+    assert_eq!(LengthPrefix::<LEu32>::PREFIX_LEN, 4);
+    let mut buf = [0; 12];
+    let mut writer = EndianWriter::new(&mut buf);
+    writer.write_frame(&framing, b"abc").unwrap();
+    writer
+        .write_frame(&LengthPrefix::<u8>::including_prefix(), b"xy")
+        .unwrap();
+    let error = writer.write_frame(&framing, b"z").unwrap_err();
+    assert_eq!(
+        (error.kind(), error.offset()),
+        (ErrorKind::Truncated, Some(10))
+    );
+    assert_eq!(writer.position(), 10);
+    let error = writer
+        .write_frame(&LengthPrefix::<u8>::new(), &[0; 256])
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfRange);
+    assert_eq!(&buf[..10], b"\x03\x00\x00\x00abc\x03xy");
+
+    assert_eq!(framing.frame_len(&buf[..3]), Ok(None));
+    assert_eq!(framing.frame_len(&buf[..4]), Ok(Some(7)));
+    let error = framing.decode(&buf[..6]).unwrap_err();
+    assert_eq!((error.expected(), error.actual()), (Some(7), Some(6)));
+    let error = LengthPrefix::<BEu16>::including_prefix()
+        .decode(&[0, 1])
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::OutOfRange);
+    assert!(LengthPrefix::<BEu64>::new().frame_len(&[0xff; 8]).is_err());
+
+    let mut reader = EndianReader::new(&buf[..10]);
+    assert_eq!(reader.read_frame(&framing), Ok(&b"abc"[..]));
+    assert_eq!(
+        reader.read_frame(&LengthPrefix::<u8>::including_prefix()),
+        Ok(&b"xy"[..])
+    );
+    let mut reader = EndianReader::new(&buf[..9]);
+    reader.skip(7).unwrap();
+    let error = reader
+        .read_frame(&LengthPrefix::<u8>::including_prefix())
+        .unwrap_err();
+    assert_eq!(
+        (error.kind(), error.offset()),
+        (ErrorKind::Truncated, Some(7))
+    );
+    assert_eq!(reader.position(), 7);
+}
+
+#[test]
+fn layered_headers() {
+    #[repr(C)]
+    #[derive(Copy, Clone, Debug)]
+    struct Ipv4 {
+        version_ihl: u8,
+        tos: u8,
+        len: Unaligned<BEu16>,
+        id: Unaligned<BEu16>,
+        fragment: Unaligned<BEu16>,
+        ttl: u8,
+        protocol: u8,
+        checksum: Unaligned<BEu16>,
+        src: [u8; 4],
+        dst: [u8; 4],
+    }
+    unsafe impl Header for Ipv4 {}
+
+    let packet = [
+        0x46, 0, 0, 30, 0, 1, 0, 0, 64, 17, 0, 0, 10, 0, 0, 1, 10, 0, 0, 2, 1, 2, 3, 4, 0xaa, 0xbb,
+    ];
+    let mut reader = EndianReader::new(&packet);
+    let ip = reader.read_header::<Ipv4>().unwrap();
+    assert_eq!(
+        (ip.len.get().value(), ip.protocol, ip.dst),
+        (30, 17, [10, 0, 0, 2])
+    );
+    let options = reader
+        .read_bytes(usize::from(ip.version_ihl & 0xf) * 4 - 20)
+        .unwrap();
+    assert_eq!(options, [1, 2, 3, 4]);
+    assert_eq!(reader.remaining_bytes(), [0xaa, 0xbb]);
+    let error = reader.read_header::<Ipv4>().unwrap_err();
+    assert_eq!(
+        (error.kind(), error.offset()),
+        (ErrorKind::Truncated, Some(24))
+    );
+    assert_eq!(reader.position(), 24);
+
+    let words = [0u32; 2];
+    let bytes: &[u8; 8] = unsafe { transmute(&words) };
+    let (word, rest) = split_header::<BEu32>(bytes).unwrap();
+    assert_eq!((*word, rest.len()), (BEu32::new(0), 4));
+    let error = split_header::<BEu32>(&bytes[1..]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Misaligned);
+    let (magic, _) = split_header::<Magic<2>>(&bytes[1..]).unwrap();
+    assert_eq!(*magic, [0, 0]);
+}
+
+#[test]
+fn frame_parser() {
+    // Frames with a 1-byte type, and a little-endian length of the whole frame at offset 1
+    let framing = |bytes: &[u8]| -> Result<Option<usize>, Error> {
+        match Field::<LEu16, 1>::read(bytes) {
+            Ok(len) => Ok(Some(len.value().into())),
+            Err(_) => Ok(None),
+        }
+    };
+    let stream = [1, 4, 0, 0xaa, 2, 3, 0, 3, 6, 0, 1, 2, 3];
+    for chunk_len in 1..stream.len() {
+        let mut buf = [0; 8];
+        let mut parser = FrameParser::new(framing, &mut buf);
+        let mut frames = std::vec::Vec::new();
+        for chunk in stream.chunks(chunk_len) {
+            let mut chunk = chunk;
+            while !chunk.is_empty() {
+                chunk = &chunk[parser.push(chunk)..];
+                while let Some(frame) = parser.next_frame().unwrap() {
+                    frames.push(frame.to_vec());
+                }
+            }
+        }
+        assert_eq!(frames, [&stream[..4], &stream[4..7], &stream[7..]]);
+        assert_eq!(parser.buffered(), 0);
+    }
+
+    let mut buf = [0; 4];
+    let mut parser = FrameParser::new(LengthPrefix::<u8>::new(), &mut buf);
+    assert_eq!(parser.push(&[4, 0, 0, 0, 0]), 4);
+    let error = parser.next_frame().unwrap_err();
+    assert_eq!(
+        (error.kind(), error.expected(), error.actual()),
+        (ErrorKind::OutOfRange, Some(4), Some(5))
+    );
+    let mut parser = FrameParser::new(LengthPrefix::<u8>::including_prefix(), &mut buf);
+    parser.push(&[1, 0]);
+    assert_eq!(parser.next_frame(), Ok(Some(&[1][..])));
+    assert_eq!(
+        parser.next_frame().unwrap_err().kind(),
+        ErrorKind::OutOfRange
+    );
+}
+
+#[test]
+fn timestamps() {
+    use core::convert::TryFrom;
+    use core::time::Duration;
+
+    let time = BEtime32::from_bytes([0x69, 0x2d, 0x4b, 0x00]);
+    assert_eq!(time.secs(), 1_764_576_000);
+    assert_eq!(time.to_duration(), Some(Duration::from_secs(1_764_576_000)));
+    assert_eq!(
+        LEtime32::from_duration(Duration::from_millis(1_500)).map(LEtime32::to_bytes),
+        Some([1, 0, 0, 0])
+    );
+    assert_eq!(BEtime32::from_duration(Duration::from_secs(1 << 32)), None);
+    assert_eq!(BEtime64::from_secs(-1).to_duration(), None);
+    assert_eq!(
+        Duration::try_from(BEtime64::from_secs(-1)),
+        Err(Error::new(ErrorKind::OutOfRange))
+    );
+    assert_eq!(
+        BEtime32::try_from(Duration::from_secs(1 << 32)),
+        Err(Error::new(ErrorKind::OutOfRange).values(u32::MAX as u64, 1 << 32))
+    );
+    assert!(LEtime64::from_secs(-1) < LEtime64::from_secs(0));
+    assert_eq!(format!("{:?}", time), "1764576000");
+}
+
+#[test]
+fn fixed_point() {
+    let value = BEq16_16::from_bytes([0x00, 0x01, 0x80, 0x00]);
+    assert_eq!(value.to_f64(), 1.5);
+    assert_eq!(value.to_bits(), 0x1_8000);
+    assert_eq!(
+        BEq16_16::from_f32(-2.25).to_bytes(),
+        [0xff, 0xfd, 0xc0, 0x00]
+    );
+    assert_eq!(LEq8_24::from_f64(1.0).to_bytes(), [0x00, 0x00, 0x00, 0x01]);
+    assert_eq!(BEq2_14::from_f64(-0.000061).to_bytes(), [0xff, 0xff]);
+    assert_eq!(LEq1_15::from_f64(1.0).to_bits(), i16::MAX);
+    assert_eq!(format!("{}", value), "1.5");
+
+    let half = BEq16_16::from_f64(0.5);
+    assert_eq!((value + half).to_f64(), 2.0);
+    assert_eq!((value - half).to_f64(), 1.0);
+    assert_eq!((value * BEq16_16::from_f64(-3.0)).to_f64(), -4.5);
+    assert_eq!((value / half).to_f64(), 3.0);
+    assert!(half < value);
+    assert_eq!(BigEndian::<Fixed<u8, 4>>::from_f32(2.5).to_bytes(), [0x28]);
+}
+
 #[test]
 fn example() {
     #[repr(C)]
@@ -181,6 +2439,7 @@ fn example() {
         size: BEu64,
         thing: BEu64,
     }
+    #[allow(unused_variables, unused_mut)]
     impl Data {
         pub const SIZE: usize = core::mem::size_of::<Self>();
         pub const MAGIC: u32 = 0x1337_beef;
@@ -0,0 +1,76 @@
+use crate::{EndianLength, EndianReader, EndianValue, Error, ErrorKind};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// Iterator over the records of a type-length-value encoded buffer, with tags of type `Tag` and
+/// lengths of type `Len` (the length of the value, not including the tag and length)
+///
+/// Each record is yielded as its tag and value bytes. A record which runs past the end of the buffer
+/// is yielded as an error, after which iteration stops.
+///
+/// ```
+/// use storage_endian::{BEu16, Tlv};
+///
+/// let records = b"\x00\x01\x00\x02hi\x00\x02\x00\x00\x00\x03\x00\x09oops";
+/// let mut tlv = Tlv::<BEu16, BEu16>::new(records);
+/// assert_eq!(tlv.next(), Some(Ok((BEu16::new(1), &b"hi"[..]))));
+/// assert_eq!(tlv.next(), Some(Ok((BEu16::new(2), &b""[..]))));
+/// let error = tlv.next().unwrap().unwrap_err();
+/// assert_eq!((error.offset(), error.expected(), error.actual()), (Some(14), Some(9), Some(4)));
+/// assert_eq!(tlv.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tlv<'a, Tag, Len> {
+    reader: EndianReader<&'a [u8]>,
+    failed: bool,
+    _marker: PhantomData<(Tag, Len)>,
+}
+
+impl<'a, Tag: EndianValue, Len: EndianLength> Tlv<'a, Tag, Len> {
+    /// Creates an iterator over the records in `bytes`
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            reader: EndianReader::new(bytes),
+            failed: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the offset of the next record
+    pub fn offset(&self) -> usize {
+        self.reader.position()
+    }
+
+    /// Returns the bytes of the remaining records
+    pub fn remaining_bytes(&self) -> &'a [u8] {
+        self.reader.remaining_bytes()
+    }
+
+    // Reads the next record, consuming nothing on error
+    fn record(&mut self) -> Result<(Tag, &'a [u8]), Error> {
+        let mut reader = self.reader.clone();
+        let tag = reader.read::<Tag>()?;
+        let len = reader.read::<Len>()?;
+        let offset = reader.position();
+        let len = len
+            .to_len()
+            .ok_or(Error::new(ErrorKind::OutOfRange).at(offset))?;
+        let value = reader.read_bytes(len)?;
+        self.reader = reader;
+        Ok((tag, value))
+    }
+}
+
+impl<'a, Tag: EndianValue, Len: EndianLength> Iterator for Tlv<'a, Tag, Len> {
+    type Item = Result<(Tag, &'a [u8]), Error>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.reader.is_empty() {
+            return None;
+        }
+        let record = self.record();
+        self.failed = record.is_err();
+        Some(record)
+    }
+}
+
+impl<Tag: EndianValue, Len: EndianLength> FusedIterator for Tlv<'_, Tag, Len> {}
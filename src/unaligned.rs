@@ -0,0 +1,76 @@
+use core::fmt::{Debug, Display};
+use core::ptr::{addr_of, addr_of_mut};
+
+#[repr(C, packed)]
+#[derive(Copy, Clone, Default)]
+/// Wrapper with an alignment of 1 (e.g. `Unaligned<BEu32>`), accessed with unaligned reads and writes
+///
+/// This can be used inside `#[repr(C, packed)]` structs, where taking a reference to a field of an
+/// aligned type such as `BEu32` is not allowed.
+pub struct Unaligned<T>(T);
+
+impl<T: Copy> Unaligned<T> {
+    /// Creates a new value
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the wrapped value
+    #[inline(always)]
+    pub fn get(&self) -> T {
+        // SAFETY: the pointer is valid for reads and `read_unaligned` has no alignment requirement
+        unsafe { addr_of!(self.0).read_unaligned() }
+    }
+
+    /// Replaces the wrapped value
+    #[inline(always)]
+    pub fn set(&mut self, value: T) {
+        // SAFETY: the pointer is valid for writes and `write_unaligned` has no alignment requirement
+        unsafe { addr_of_mut!(self.0).write_unaligned(value) }
+    }
+
+    /// Updates the wrapped value in place
+    pub fn update(&mut self, f: impl FnOnce(T) -> T) {
+        self.set(f(self.get()))
+    }
+}
+
+impl<T: Copy> From<T> for Unaligned<T> {
+    #[inline(always)]
+    fn from(other: T) -> Self {
+        Self(other)
+    }
+}
+
+impl<T: Copy + PartialEq> PartialEq for Unaligned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Copy + Eq> Eq for Unaligned<T> {}
+
+impl<T: Copy + PartialOrd> PartialOrd for Unaligned<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.get().partial_cmp(&other.get())
+    }
+}
+
+impl<T: Copy + Ord> Ord for Unaligned<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.get().cmp(&other.get())
+    }
+}
+
+impl<T: Copy + Debug> Debug for Unaligned<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<T: Copy + Display> Display for Unaligned<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Display::fmt(&self.get(), f)
+    }
+}
@@ -0,0 +1,111 @@
+use core::char::{decode_utf16, DecodeUtf16Error};
+use core::fmt::{Debug, Display, Write};
+
+#[derive(Copy, Clone)]
+/// View over a string of UTF-16 code units stored with an explicit endianess (e.g. `&[LEu16]`)
+pub struct WideStr<'a, W> {
+    units: &'a [W],
+}
+
+impl<'a, W: Copy> WideStr<'a, W>
+where
+    u16: From<W>,
+{
+    /// Creates a view over a slice of code units
+    pub const fn new(units: &'a [W]) -> Self {
+        Self { units }
+    }
+
+    /// Creates a view over a slice of code units, stopping at the first NUL code unit if any
+    pub fn until_nul(units: &'a [W]) -> Self {
+        let len = units
+            .iter()
+            .position(|u| u16::from(*u) == 0)
+            .unwrap_or(units.len());
+        Self {
+            units: &units[..len],
+        }
+    }
+
+    /// Returns the underlying code units
+    pub const fn units(&self) -> &'a [W] {
+        self.units
+    }
+
+    /// Returns the length in code units
+    pub const fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    /// Returns `true` if there are no code units
+    pub const fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// Returns an iterator over the native code units
+    pub fn code_units(&self) -> impl Iterator<Item = u16> + 'a {
+        self.units.iter().map(|u| u16::from(*u))
+    }
+
+    /// Returns an iterator decoding the characters, yielding an error for each unpaired surrogate
+    pub fn chars(&self) -> impl Iterator<Item = Result<char, DecodeUtf16Error>> + 'a {
+        decode_utf16(self.code_units())
+    }
+
+    /// Returns an iterator decoding the characters, replacing unpaired surrogates with `U+FFFD`
+    pub fn chars_lossy(&self) -> impl Iterator<Item = char> + 'a {
+        self.chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+}
+
+impl<W: Copy> PartialEq<str> for WideStr<'_, W>
+where
+    u16: From<W>,
+{
+    fn eq(&self, other: &str) -> bool {
+        self.code_units().eq(other.encode_utf16())
+    }
+}
+
+impl<W: Copy> PartialEq<&str> for WideStr<'_, W>
+where
+    u16: From<W>,
+{
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<W: Copy, V: Copy> PartialEq<WideStr<'_, V>> for WideStr<'_, W>
+where
+    u16: From<W> + From<V>,
+{
+    fn eq(&self, other: &WideStr<'_, V>) -> bool {
+        self.code_units().eq(other.code_units())
+    }
+}
+
+impl<W: Copy> Display for WideStr<'_, W>
+where
+    u16: From<W>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.chars_lossy().try_for_each(|c| f.write_char(c))
+    }
+}
+
+impl<W: Copy> Debug for WideStr<'_, W>
+where
+    u16: From<W>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_char('"')?;
+        for c in self.chars_lossy() {
+            for e in c.escape_debug() {
+                f.write_char(e)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
@@ -0,0 +1,117 @@
+use core::fmt::{Debug, Display};
+
+// Formats the fields of a UUID in the standard hyphenated form
+fn fmt_fields(
+    (d1, d2, d3, d4): (u32, u16, u16, [u8; 8]),
+    f: &mut core::fmt::Formatter,
+) -> core::fmt::Result {
+    write!(
+        f,
+        "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-",
+        d1, d2, d3, d4[0], d4[1]
+    )?;
+    d4[2..].iter().try_for_each(|b| write!(f, "{:02x}", b))
+}
+
+macro_rules! uuid {
+    ( $( $(#[$attr:meta])* $Uuid:ident($to:ident, $from:ident) ),* ) => { $(
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct $Uuid([u8; 16]);
+
+        impl $Uuid {
+            /// The nil UUID (all bits zero)
+            pub const NIL: Self = Self([0; 16]);
+
+            /// Creates a value from its in-memory byte representation
+            #[inline(always)]
+            pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+                Self(bytes)
+            }
+            /// Returns the in-memory byte representation of the value
+            #[inline(always)]
+            pub const fn to_bytes(self) -> [u8; 16] {
+                self.0
+            }
+            /// Creates a value from the (time_low, time_mid, time_hi_and_version, remaining bytes) fields
+            pub const fn from_fields(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Self {
+                let (a, b, c) = (d1.$to(), d2.$to(), d3.$to());
+                Self([
+                    a[0], a[1], a[2], a[3], b[0], b[1], c[0], c[1],
+                    d4[0], d4[1], d4[2], d4[3], d4[4], d4[5], d4[6], d4[7],
+                ])
+            }
+            /// Returns the (time_low, time_mid, time_hi_and_version, remaining bytes) fields
+            pub const fn to_fields(self) -> (u32, u16, u16, [u8; 8]) {
+                let b = self.0;
+                (
+                    u32::$from([b[0], b[1], b[2], b[3]]),
+                    u16::$from([b[4], b[5]]),
+                    u16::$from([b[6], b[7]]),
+                    [b[8], b[9], b[10], b[11], b[12], b[13], b[14], b[15]],
+                )
+            }
+            /// Creates a value from an integer, as written in the standard hyphenated form
+            pub const fn from_u128(value: u128) -> Self {
+                let d1 = (value >> 96) as u32;
+                let d2 = (value >> 80) as u16;
+                let d3 = (value >> 64) as u16;
+                Self::from_fields(d1, d2, d3, (value as u64).to_be_bytes())
+            }
+            /// Returns the value as an integer, as written in the standard hyphenated form
+            pub const fn to_u128(self) -> u128 {
+                let (d1, d2, d3, d4) = self.to_fields();
+                (d1 as u128) << 96 | (d2 as u128) << 80 | (d3 as u128) << 64 | u64::from_be_bytes(d4) as u128
+            }
+        }
+        impl From<$Uuid> for u128 {
+            #[inline(always)]
+            fn from(other: $Uuid) -> Self {
+                other.to_u128()
+            }
+        }
+        impl From<u128> for $Uuid {
+            #[inline(always)]
+            fn from(other: u128) -> Self {
+                Self::from_u128(other)
+            }
+        }
+        impl AsRef<[u8]> for $Uuid {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl Display for $Uuid {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fmt_fields(self.to_fields(), f)
+            }
+        }
+        impl Debug for $Uuid {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                fmt_fields(self.to_fields(), f)
+            }
+        }
+    )* };
+}
+
+uuid!(
+    /// A 16-byte UUID in the RFC 4122 layout, with all fields big-endian
+    Uuid(to_be_bytes, from_be_bytes),
+    /// A 16-byte GUID in the Microsoft layout, with the first three fields little-endian
+    Guid(to_le_bytes, from_le_bytes)
+);
+
+impl From<Guid> for Uuid {
+    #[inline(always)]
+    fn from(other: Guid) -> Self {
+        Self::from_u128(other.to_u128())
+    }
+}
+
+impl From<Uuid> for Guid {
+    #[inline(always)]
+    fn from(other: Uuid) -> Self {
+        Self::from_u128(other.to_u128())
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{ByteSource, EndianReader, EndianWriter, Error, ErrorKind};
+
+/// The maximum length of an encoded 64-bit variable-length integer
+pub const MAX_VARINT_LEN: usize = 10;
+
+// Errors for an integer that's cut short or too large, reported at its first byte
+const TRUNCATED: Error = Error::new(ErrorKind::Truncated).at(0);
+const OVERFLOW: Error = Error::new(ErrorKind::OutOfRange).at(0);
+
+/// Encodes an unsigned LEB128 integer (as used by DWARF and WebAssembly), returning the bytes used in `buf`
+///
+/// ```
+/// use storage_endian::{decode_uleb128, encode_uleb128};
+///
+/// let mut buf = [0; 10];
+/// assert_eq!(encode_uleb128(624485, &mut buf), [0xe5, 0x8e, 0x26]);
+/// assert_eq!(decode_uleb128(&[0xe5, 0x8e, 0x26, 0xff]), Ok((624485, 3)));
+/// ```
+pub fn encode_uleb128(mut value: u64, buf: &mut [u8; MAX_VARINT_LEN]) -> &[u8] {
+    let mut len = 0;
+    loop {
+        let byte = value as u8 & 0x7f;
+        value >>= 7;
+        buf[len] = byte | if value != 0 { 0x80 } else { 0 };
+        len += 1;
+        if value == 0 {
+            return &buf[..len];
+        }
+    }
+}
+
+/// Encodes a signed LEB128 integer, returning the bytes used in `buf`
+pub fn encode_sleb128(mut value: i64, buf: &mut [u8; MAX_VARINT_LEN]) -> &[u8] {
+    let mut len = 0;
+    loop {
+        let byte = value as u8 & 0x7f;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        buf[len] = byte | if done { 0 } else { 0x80 };
+        len += 1;
+        if done {
+            return &buf[..len];
+        }
+    }
+}
+
+/// Encodes a variable-length quantity (as used by MIDI files), most significant group first,
+/// returning the bytes used in `buf`
+///
+/// ```
+/// use storage_endian::{decode_vlq, encode_vlq};
+///
+/// let mut buf = [0; 10];
+/// assert_eq!(encode_vlq(0x0fff_ffff, &mut buf), [0xff, 0xff, 0xff, 0x7f]);
+/// assert_eq!(decode_vlq(&[0x81, 0x00]), Ok((0x80, 2)));
+/// ```
+pub fn encode_vlq(value: u64, buf: &mut [u8; MAX_VARINT_LEN]) -> &[u8] {
+    let bits = 64 - value.leading_zeros() as usize;
+    let len = bits.div_ceil(7).max(1);
+    for (i, byte) in buf[..len].iter_mut().enumerate() {
+        let group = len - 1 - i;
+        *byte = (value >> (7 * group)) as u8 & 0x7f | if group != 0 { 0x80 } else { 0 };
+    }
+    &buf[..len]
+}
+
+/// Decodes an unsigned LEB128 integer from the start of `bytes`, returning it and its length
+///
+/// Errors are reported at offset 0, the start of the integer.
+pub fn decode_uleb128(bytes: &[u8]) -> Result<(u64, usize), Error> {
+    let mut value = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let bits = (byte & 0x7f) as u64;
+        if shift >= 64 || (shift == 63 && bits > 1) {
+            return Err(OVERFLOW);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(TRUNCATED)
+}
+
+/// Decodes a signed LEB128 integer from the start of `bytes`, returning it and its length
+///
+/// Errors are reported as for `decode_uleb128`.
+pub fn decode_sleb128(bytes: &[u8]) -> Result<(i64, usize), Error> {
+    let mut value = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let bits = (byte & 0x7f) as i64;
+        if shift >= 64 || (shift == 63 && bits != 0 && bits != 0x7f) {
+            return Err(OVERFLOW);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            if shift < 57 && byte & 0x40 != 0 {
+                // Sign extend
+                value |= -1 << (shift + 7);
+            }
+            return Ok((value, i + 1));
+        }
+    }
+    Err(TRUNCATED)
+}
+
+/// Decodes a variable-length quantity from the start of `bytes`, returning it and its length
+///
+/// Errors are reported as for `decode_uleb128`.
+pub fn decode_vlq(bytes: &[u8]) -> Result<(u64, usize), Error> {
+    let mut value: u64 = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        if value >> 57 != 0 {
+            return Err(OVERFLOW);
+        }
+        value = value << 7 | (byte & 0x7f) as u64;
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(TRUNCATED)
+}
+
+/// ZigZag encoding of signed integers, which maps small magnitudes to small unsigned values
+/// (0, -1, 1, -2, ... to 0, 1, 2, 3, ...) so that they encode to short varints
+///
+/// ```
+/// use storage_endian::ZigZag;
+///
+/// assert_eq!((-2i32).zigzag_encode(), 3u32);
+/// assert_eq!(i32::zigzag_decode(3), -2);
+/// assert_eq!(i64::MIN.zigzag_encode(), u64::MAX);
+/// ```
+pub trait ZigZag: Copy {
+    /// The unsigned type of the same size
+    type Unsigned;
+    /// Returns the ZigZag encoding of the value
+    fn zigzag_encode(self) -> Self::Unsigned;
+    /// Returns the value for a ZigZag encoding
+    fn zigzag_decode(value: Self::Unsigned) -> Self;
+}
+
+macro_rules! zigzag {
+    ( $( $t:ident / $u:ident ),* ) => { $(
+        impl ZigZag for $t {
+            type Unsigned = $u;
+            fn zigzag_encode(self) -> $u {
+                ((self << 1) ^ (self >> ($t::BITS - 1))) as $u
+            }
+            fn zigzag_decode(value: $u) -> $t {
+                ((value >> 1) as $t) ^ -((value & 1) as $t)
+            }
+        }
+    )* };
+}
+
+zigzag!(
+    i8 / u8,
+    i16 / u16,
+    i32 / u32,
+    i64 / u64,
+    i128 / u128,
+    isize / usize
+);
+
+// Decodes a variable-length integer from the start of a slice, returning it and its length
+type Decoder<T> = fn(&[u8]) -> Result<(T, usize), Error>;
+
+impl<S: ByteSource> EndianReader<S> {
+    // Reads a variable-length integer, decoding directly from the source when it's contiguous
+    fn read_varint<T>(&mut self, decode: Decoder<T>) -> Result<T, Error> {
+        // Errors are reported at the start of the integer
+        let offset = self.position();
+        let error = |e: Error| e.at(offset);
+        let (chunk_len, result) = {
+            let chunk = self.source_mut().fill();
+            (chunk.len(), decode(chunk))
+        };
+        match result {
+            Ok((value, len)) => {
+                self.skip(len)?;
+                return Ok(value);
+            }
+            Err(e)
+                if e.kind() == ErrorKind::Truncated && self.remaining_hint() != Some(chunk_len) => {
+            }
+            Err(e) => return Err(error(e)),
+        }
+        // The integer may span chunks, so gather it a byte at a time
+        let mut buf = [0; MAX_VARINT_LEN];
+        for len in 1..=MAX_VARINT_LEN {
+            buf[len - 1] = self.read::<u8>().map_err(|_| error(TRUNCATED))?;
+            if buf[len - 1] & 0x80 == 0 {
+                return decode(&buf[..len]).map(|(value, _)| value).map_err(error);
+            }
+        }
+        Err(error(OVERFLOW))
+    }
+
+    /// Reads an unsigned LEB128 integer
+    ///
+    /// Errors are reported at the offset of the first byte of the integer. On error nothing is consumed if
+    /// the source knows how many bytes it has left (see `ByteSource`).
+    pub fn read_uleb128(&mut self) -> Result<u64, Error> {
+        self.read_varint(decode_uleb128)
+    }
+
+    /// Reads a signed LEB128 integer
+    ///
+    /// On error nothing is consumed, as for `read_uleb128`.
+    pub fn read_sleb128(&mut self) -> Result<i64, Error> {
+        self.read_varint(decode_sleb128)
+    }
+
+    /// Reads a variable-length quantity
+    ///
+    /// On error nothing is consumed, as for `read_uleb128`.
+    pub fn read_vlq(&mut self) -> Result<u64, Error> {
+        self.read_varint(decode_vlq)
+    }
+
+    /// Reads a ZigZag encoded unsigned LEB128 integer (a protobuf `sint64`)
+    ///
+    /// On error nothing is consumed, as for `read_uleb128`.
+    pub fn read_zigzag(&mut self) -> Result<i64, Error> {
+        self.read_uleb128().map(i64::zigzag_decode)
+    }
+}
+
+impl EndianWriter<'_> {
+    /// Writes an unsigned LEB128 integer
+    ///
+    /// On error nothing is written.
+    pub fn write_uleb128(&mut self, value: u64) -> Result<(), Error> {
+        self.write_bytes(encode_uleb128(value, &mut [0; MAX_VARINT_LEN]))
+    }
+
+    /// Writes a signed LEB128 integer
+    ///
+    /// On error nothing is written.
+    pub fn write_sleb128(&mut self, value: i64) -> Result<(), Error> {
+        self.write_bytes(encode_sleb128(value, &mut [0; MAX_VARINT_LEN]))
+    }
+
+    /// Writes a variable-length quantity
+    ///
+    /// On error nothing is written.
+    pub fn write_vlq(&mut self, value: u64) -> Result<(), Error> {
+        self.write_bytes(encode_vlq(value, &mut [0; MAX_VARINT_LEN]))
+    }
+
+    /// Writes a ZigZag encoded unsigned LEB128 integer (a protobuf `sint64`)
+    ///
+    /// On error nothing is written.
+    pub fn write_zigzag(&mut self, value: i64) -> Result<(), Error> {
+        self.write_uleb128(value.zigzag_encode())
+    }
+}
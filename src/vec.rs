@@ -0,0 +1,67 @@
+use crate::{ByteOrder, Endian, Endianness, Error, Primitive};
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+
+impl<T: Primitive, E: Endianness> Endian<T, E> {
+    /// Copies a byte slice into a new vector of values, which must be a whole number of values long
+    ///
+    /// The bytes don't need to be aligned, as they're copied rather than reused.
+    pub fn vec_from_bytes(bytes: &[u8]) -> Result<Vec<Self>, Error> {
+        if bytes.len() % core::mem::size_of::<Self>() != 0 {
+            return Err(Error::partial::<Self>(bytes.len()));
+        }
+        let len = bytes.len() / core::mem::size_of::<Self>();
+        let mut values = Vec::<Self>::with_capacity(len);
+        // SAFETY: the vector has capacity for `bytes`, and every bit pattern is a valid primitive number
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                values.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+            values.set_len(len);
+        }
+        Ok(values)
+    }
+    /// Copies the in-memory (storage order) bytes of a slice of values into a new vector
+    pub fn vec_to_bytes(values: &[Self]) -> Vec<u8> {
+        // SAFETY: primitive numbers have no padding and a byte slice has an alignment of 1
+        unsafe {
+            core::slice::from_raw_parts(
+                values.as_ptr() as *const u8,
+                core::mem::size_of_val(values),
+            )
+        }
+        .to_vec()
+    }
+    /// Converts a vector of values into a vector of native values, reusing the allocation
+    pub fn into_native_vec(values: Vec<Self>) -> Vec<T> {
+        let mut values = ManuallyDrop::new(values);
+        if E::ORDER != ByteOrder::NATIVE {
+            Self::slice_to_native(&mut values);
+        }
+        // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`, so the allocation has the same layout
+        unsafe {
+            Vec::from_raw_parts(
+                values.as_mut_ptr() as *mut T,
+                values.len(),
+                values.capacity(),
+            )
+        }
+    }
+    /// Converts a vector of native values into a vector of values, reusing the allocation
+    pub fn from_native_vec(values: Vec<T>) -> Vec<Self> {
+        let mut values = ManuallyDrop::new(values);
+        if E::ORDER != ByteOrder::NATIVE {
+            Self::slice_from_native(&mut values);
+        }
+        // SAFETY: `Endian<T, E>` is a transparent wrapper of `T`, so the allocation has the same layout
+        unsafe {
+            Vec::from_raw_parts(
+                values.as_mut_ptr() as *mut Self,
+                values.len(),
+                values.capacity(),
+            )
+        }
+    }
+}
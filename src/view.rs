@@ -0,0 +1,136 @@
+use crate::{EndianValue, Error};
+
+// Returns the `len` bytes at `offset` within `bytes`
+fn range(bytes: &[u8], offset: usize, len: usize) -> Result<core::ops::Range<usize>, Error> {
+    match offset.checked_add(len) {
+        Some(end) if end <= bytes.len() => Ok(offset..end),
+        _ => Err(Error::truncated(
+            offset,
+            len,
+            bytes.len().saturating_sub(offset),
+        )),
+    }
+}
+
+/// A random-access view of a byte buffer, reading values at arbitrary offsets (like JavaScript's `DataView`)
+///
+/// Accesses are bounds-checked and have no alignment requirement.
+///
+/// ```
+/// use storage_endian::{BEu16, BEu32, EndianView};
+///
+/// let table = b"\x00\x01\x00\x00\x00\x0a\x00\x00\x00\x04";
+/// let view = EndianView::new(table);
+/// let count = view.get::<BEu16>(0)?.value();
+/// let offset = view.get::<BEu32>(2)?.value();
+/// assert_eq!((count, offset), (1, 10));
+/// assert!(view.get::<BEu32>(offset as usize).is_err());
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct EndianView<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> EndianView<'a> {
+    /// Creates a view of `bytes`
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the length of the buffer in bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the underlying bytes
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the `len` bytes at `offset`
+    pub fn get_bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], Error> {
+        Ok(&self.bytes[range(self.bytes, offset, len)?])
+    }
+
+    /// Reads a value from its storage order bytes at `offset`, e.g. `view.get::<BEu32>(8)`
+    pub fn get<V: EndianValue>(&self, offset: usize) -> Result<V, Error> {
+        let mut bytes = V::Bytes::default();
+        let len = bytes.as_ref().len();
+        bytes.as_mut().copy_from_slice(self.get_bytes(offset, len)?);
+        Ok(V::from_storage(bytes))
+    }
+}
+
+/// A random-access mutable view of a byte buffer, reading and writing values at arbitrary offsets
+///
+/// Accesses are bounds-checked and have no alignment requirement.
+///
+/// ```
+/// use storage_endian::{EndianViewMut, LEu16};
+///
+/// let mut header = [0; 4];
+/// let mut view = EndianViewMut::new(&mut header);
+/// view.set::<LEu16>(2, LEu16::new(0x1234))?;
+/// assert_eq!(view.get::<LEu16>(2)?, 0x1234);
+/// assert!(view.set(3, LEu16::new(0)).is_err());
+/// assert_eq!(header, [0, 0, 0x34, 0x12]);
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct EndianViewMut<'a> {
+    bytes: &'a mut [u8],
+}
+
+impl<'a> EndianViewMut<'a> {
+    /// Creates a mutable view of `bytes`
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns a shared view of the same buffer
+    pub fn as_view(&self) -> EndianView<'_> {
+        EndianView::new(self.bytes)
+    }
+
+    /// Returns the length of the buffer in bytes
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the underlying bytes
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+
+    /// Reads a value from its storage order bytes at `offset`
+    pub fn get<V: EndianValue>(&self, offset: usize) -> Result<V, Error> {
+        self.as_view().get(offset)
+    }
+
+    /// Writes raw bytes at `offset`
+    ///
+    /// On error nothing is written.
+    pub fn set_bytes(&mut self, offset: usize, bytes: &[u8]) -> Result<(), Error> {
+        let range = range(self.bytes, offset, bytes.len())?;
+        self.bytes[range].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes the storage order bytes of a value at `offset`, e.g. `view.set::<LEu16>(4, value)`
+    ///
+    /// On error nothing is written.
+    pub fn set<V: EndianValue>(&mut self, offset: usize, value: V) -> Result<(), Error> {
+        self.set_bytes(offset, value.to_storage().as_ref())
+    }
+}
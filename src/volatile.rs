@@ -0,0 +1,44 @@
+use core::cell::UnsafeCell;
+use core::fmt::Debug;
+
+#[repr(transparent)]
+/// Wrapper for a memory-mapped register (e.g. `Volatile<BEu32>`), accessed with volatile reads and writes
+///
+/// Volatile accesses are never elided, merged or reordered with other volatile accesses by the compiler.
+pub struct Volatile<T>(UnsafeCell<T>);
+
+impl<T: Copy> Volatile<T> {
+    /// Creates a new value
+    pub const fn new(value: T) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    /// Performs a volatile read of the register
+    pub fn read(&self) -> T {
+        // SAFETY: the pointer comes from a reference, so it is valid and aligned for reads
+        unsafe { self.0.get().read_volatile() }
+    }
+
+    /// Performs a volatile write of the register
+    pub fn write(&self, value: T) {
+        // SAFETY: the pointer comes from a reference, so it is valid and aligned for writes
+        unsafe { self.0.get().write_volatile(value) }
+    }
+
+    /// Performs a volatile read, followed by a volatile write of the updated value
+    pub fn modify(&self, f: impl FnOnce(T) -> T) {
+        self.write(f(self.read()))
+    }
+}
+
+impl<T: Copy> From<T> for Volatile<T> {
+    fn from(other: T) -> Self {
+        Self::new(other)
+    }
+}
+
+impl<T: Copy + Debug> Debug for Volatile<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        Debug::fmt(&self.read(), f)
+    }
+}
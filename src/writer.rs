@@ -0,0 +1,88 @@
+use crate::{EndianValue, Error};
+
+/// A cursor writing values sequentially into a mutable byte slice
+///
+/// ```
+/// use storage_endian::{BEu16, EndianWriter, LEu32};
+///
+/// let mut frame = [0; 8];
+/// let mut writer = EndianWriter::new(&mut frame);
+/// writer.write(BEu16::new(0x1234))?;
+/// writer.skip(1)?;
+/// writer.write_bytes(b"a")?;
+/// writer.write(LEu32::new(7))?;
+/// assert_eq!(writer.position(), 8);
+/// assert!(writer.write(0u8).is_err());
+/// assert_eq!(frame, *b"\x12\x34\x00a\x07\x00\x00\x00");
+/// # Ok::<(), storage_endian::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct EndianWriter<'a> {
+    bytes: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> EndianWriter<'a> {
+    /// Creates a writer starting at the beginning of `bytes`
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Returns the offset of the next byte to be written
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes left to write
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// Returns `true` if there is no space left to write
+    pub fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the bytes written so far
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[..self.position]
+    }
+
+    /// Returns the bytes written so far, consuming the writer
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.bytes[..self.position]
+    }
+
+    // Returns the next `len` bytes and advances past them
+    fn advance(&mut self, len: usize) -> Result<&mut [u8], Error> {
+        let position = self.position;
+        let remaining = self.remaining();
+        let bytes = self.bytes[position..]
+            .get_mut(..len)
+            .ok_or(Error::truncated(position, len, remaining))?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Writes raw bytes
+    ///
+    /// On error nothing is written.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.advance(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes the storage order bytes of a value, e.g. `writer.write(BEu32::new(7))`
+    ///
+    /// On error nothing is written.
+    pub fn write<V: EndianValue>(&mut self, value: V) -> Result<(), Error> {
+        self.write_bytes(value.to_storage().as_ref())
+    }
+
+    /// Skips the next `len` bytes, leaving their contents unchanged
+    ///
+    /// On error nothing is skipped.
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.advance(len).map(|_| ())
+    }
+}
@@ -0,0 +1,108 @@
+//! Checks the code generated for the probe functions in `examples/codegen.rs`.
+//!
+//! The example is built in release mode with `--emit asm` (into its own target directory, so it doesn't
+//! contend with the build running this test), and each probe is checked for the exact number of byte
+//! swap instructions and for not calling out to anything. Only x86-64 is checked, where the mnemonics
+//! are known; other targets can still inspect the example by hand.
+#![cfg(all(target_arch = "x86_64", target_endian = "little"))]
+
+use std::path::Path;
+use std::process::Command;
+
+// Each probe, and the number of byte swaps it should compile to
+const PROBES: &[(&str, usize)] = &[
+    ("probe_be_u8_value", 0),
+    ("probe_be_u16_value", 1),
+    ("probe_be_u32_value", 1),
+    ("probe_be_u64_new", 1),
+    ("probe_le_u32_value", 0),
+    ("probe_ne_u64_value", 0),
+    ("probe_be_u32_add", 3),
+    ("probe_be_u32_eq", 1),
+    ("probe_be_u32_lt", 2),
+    ("probe_be_u32_and", 0),
+    ("probe_be_u32_chain", 4),
+    ("probe_be_u32_chain_map", 4),
+    ("probe_be_u32_eq_wrapper", 0),
+    ("probe_be_u128_value", 2),
+    ("probe_be_u32_read", 1),
+];
+
+// The instructions of `name`, up to the end of the function
+fn instructions<'a>(asm: &'a str, name: &str) -> Vec<&'a str> {
+    let label = format!("{}:", name);
+    asm.lines()
+        .skip_while(|line| *line != label)
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with(".cfi_endproc"))
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('.') && !line.ends_with(':'))
+        .collect()
+}
+
+fn is_swap(instruction: &str) -> bool {
+    instruction.starts_with("bswap")
+        || instruction.starts_with("movbe")
+        || instruction.starts_with("rolw\t$8")
+        || instruction.starts_with("rorw\t$8")
+}
+
+#[test]
+fn probes() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("target").join("codegen");
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "rustc",
+            "--quiet",
+            "--release",
+            "--example",
+            "codegen",
+            "--target-dir",
+        ])
+        .arg(&target_dir)
+        .args(["--", "--emit", "asm"])
+        .current_dir(manifest_dir)
+        .env_remove("RUSTFLAGS")
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build the codegen example");
+
+    let examples = target_dir.join("release").join("examples");
+    let asm = std::fs::read_dir(&examples)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("codegen-") && name.ends_with(".s")
+        })
+        .max_by_key(|path| path.metadata().unwrap().modified().unwrap())
+        .expect("no assembly emitted for the codegen example");
+    let asm = std::fs::read_to_string(asm).unwrap();
+
+    for &(name, swaps) in PROBES {
+        let body = instructions(&asm, name);
+        assert!(!body.is_empty(), "{} not found", name);
+        assert_eq!(
+            body.iter()
+                .filter(|instruction| is_swap(instruction))
+                .count(),
+            swaps,
+            "{} should have {} byte swaps:\n{}",
+            name,
+            swaps,
+            body.join("\n")
+        );
+        // Local branches are fine, but calls and tail calls mean something wasn't inlined
+        let calls = |instruction: &&str| {
+            instruction.starts_with("call")
+                || (instruction.starts_with("jmp") && !instruction.contains(".LBB"))
+        };
+        assert!(
+            !body.iter().any(calls),
+            "{} should be inlined:\n{}",
+            name,
+            body.join("\n")
+        );
+    }
+}